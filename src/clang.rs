@@ -1,8 +1,10 @@
 use std::marker::PhantomData;
 
+use crate::error::{Error, Result};
+
 thread_local! {
     // no synchronization needed, since `Clang` is not sync or send
-    static CLANG_INIT_FLAG: std::cell::Cell<i32> = std::cell::Cell::new(0);
+    static CLANG_INIT_FLAG: std::cell::Cell<i32> = const { std::cell::Cell::new(0) };
 }
 
 /// `Clang` can only be created once per thread, and it is not `Sync` or `Send`.
@@ -17,15 +19,20 @@ thread_local! {
 pub struct Clang(PhantomData<*const ()>);
 
 impl Clang {
+    /// # Panics
+    ///
+    /// it panics if libclang cannot be loaded, see [`Clang::try_new`]
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_new() -> Result<Self> {
         CLANG_INIT_FLAG.with(|f| {
             if f.get() == 0 {
-                clang_sys::load().unwrap();
+                clang_sys::load().map_err(Error::LoadLibrary)?;
             }
             f.set(f.get() + 1);
-        });
-
-        Self(PhantomData)
+            Ok(Self(PhantomData))
+        })
     }
 }
 
@@ -1,9 +1,16 @@
 use std::marker::PhantomData;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::clang::Clang;
+use crate::error::{Error, Result};
 use crate::utility::{cxstring_into_string, path_to_cstring};
 
+mod compile_args;
+mod json;
+
+pub use compile_args::CompileArgs;
+pub use json::{JsonCompilationDatabase, JsonCompileCommand};
+
 #[derive(Debug)]
 pub struct CompilationDatabase<'clang> {
     raw: clang_sys::CXCompilationDatabase,
@@ -27,24 +34,30 @@ impl Clang {
     pub fn compilation_database_from_directory<P: AsRef<Path>>(
         &self,
         build_dir: P,
-    ) -> Result<CompilationDatabase<'_>, CompilationDatabaseError> {
+    ) -> Result<CompilationDatabase<'_>> {
         let mut error = clang_sys::CXCompilationDatabase_NoError;
         let raw = unsafe {
             clang_sys::clang_CompilationDatabase_fromDirectory(
-                path_to_cstring(build_dir).as_ptr(),
+                path_to_cstring(&build_dir)?.as_ptr(),
                 &mut error,
             )
         };
-        match error {
-            clang_sys::CXCompilationDatabase_NoError => Ok(CompilationDatabase {
-                raw,
-                _clang: PhantomData,
-            }),
+        let error = match error {
+            clang_sys::CXCompilationDatabase_NoError => {
+                return Ok(CompilationDatabase {
+                    raw,
+                    _clang: PhantomData,
+                })
+            }
             clang_sys::CXCompilationDatabase_CanNotLoadDatabase => {
-                Err(CompilationDatabaseError::CanNotLoadDatabase)
+                CompilationDatabaseError::CanNotLoadDatabase
             }
             e => unreachable!("unexpected CXCompilationDatabase error {}", e),
-        }
+        };
+        Err(Error::LoadDatabase {
+            build_dir: build_dir.as_ref().to_owned(),
+            error,
+        })
     }
 }
 
@@ -63,17 +76,38 @@ impl<'compile_db> Drop for CompileCommands<'compile_db> {
 }
 
 impl<'clang> CompilationDatabase<'clang> {
+    /// commands for `complete_filename`, empty if it is not in the database
+    ///
+    /// # Panics
+    ///
+    /// it panics if `complete_filename` is not a valid path, see [`CompilationDatabase::try_get_compile_commands`]
     pub fn get_compile_commands<P: AsRef<Path>>(
         &self,
         complete_filename: P,
     ) -> CompileCommands<'_> {
+        self.try_get_compile_commands(complete_filename)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+    /// commands for `complete_filename`, empty if it is not in the database
+    pub fn try_get_compile_commands<P: AsRef<Path>>(
+        &self,
+        complete_filename: P,
+    ) -> Result<CompileCommands<'_>> {
+        // null if there is no command for the file, which libclang treats as an empty set
         let raw = unsafe {
             clang_sys::clang_CompilationDatabase_getCompileCommands(
                 self.raw,
-                path_to_cstring(complete_filename).as_ptr(),
+                path_to_cstring(complete_filename)?.as_ptr(),
             )
         };
-        assert!(!raw.is_null());
+        Ok(CompileCommands {
+            raw,
+            _compile_db: PhantomData,
+        })
+    }
+    /// commands for every file in the database
+    pub fn all_compile_commands(&self) -> CompileCommands<'_> {
+        let raw = unsafe { clang_sys::clang_CompilationDatabase_getAllCompileCommands(self.raw) };
         CompileCommands {
             raw,
             _compile_db: PhantomData,
@@ -87,23 +121,91 @@ impl<'compile_commands> CompileCommands<'compile_commands> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct CompileCommand<'compile_commands> {
     pub(crate) raw: clang_sys::CXCompileCommand,
     _compile_commands: PhantomData<&'compile_commands CompileCommands<'compile_commands>>,
 }
 
 impl<'compile_commands> CompileCommands<'compile_commands> {
+    /// # Panics
+    ///
+    /// it panics if `index` is out of range, see [`CompileCommands::try_get_command`]
     pub fn get_command(&self, index: u32) -> CompileCommand<'_> {
+        self.try_get_command(index)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_get_command(&self, index: u32) -> Result<CompileCommand<'_>> {
+        // null for an index out of range, or for empty commands
         let raw = unsafe { clang_sys::clang_CompileCommands_getCommand(self.raw, index) };
-        assert!(!raw.is_null());
-        CompileCommand {
+        if raw.is_null() {
+            return Err(Error::CommandIndex {
+                index,
+                len: self.iter().len() as u32,
+            });
+        }
+        Ok(CompileCommand {
             raw,
             _compile_commands: PhantomData,
+        })
+    }
+    pub fn iter(&self) -> CompileCommandsIter<'_> {
+        CompileCommandsIter {
+            commands: self,
+            index: 0,
+            len: if self.raw.is_null() {
+                0
+            } else {
+                self.get_size()
+            },
+        }
+    }
+}
+
+/// iterator over [`CompileCommand`]s, see [`CompileCommands::iter`]
+#[derive(Debug)]
+pub struct CompileCommandsIter<'compile_commands> {
+    commands: &'compile_commands CompileCommands<'compile_commands>,
+    index: u32,
+    len: u32,
+}
+
+impl<'compile_commands> Iterator for CompileCommandsIter<'compile_commands> {
+    type Item = CompileCommand<'compile_commands>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
         }
+        let command = self.commands.get_command(self.index);
+        self.index += 1;
+        Some(command)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'compile_commands> ExactSizeIterator for CompileCommandsIter<'compile_commands> {}
+
+impl<'compile_commands> IntoIterator for &'compile_commands CompileCommands<'compile_commands> {
+    type Item = CompileCommand<'compile_commands>;
+    type IntoIter = CompileCommandsIter<'compile_commands>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
+/// a file whose contents are remapped for a compile command
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MappedSource {
+    pub path: PathBuf,
+    pub content: String,
+}
+
 impl<'compile_command> CompileCommand<'compile_command> {
     pub fn get_num_args(&self) -> u32 {
         unsafe { clang_sys::clang_CompileCommand_getNumArgs(self.raw) }
@@ -111,6 +213,37 @@ impl<'compile_command> CompileCommand<'compile_command> {
     pub fn get_arg(&self, index: u32) -> String {
         unsafe { cxstring_into_string(clang_sys::clang_CompileCommand_getArg(self.raw, index)) }
     }
+    /// the working directory the command runs in
+    pub fn directory(&self) -> PathBuf {
+        PathBuf::from(unsafe {
+            cxstring_into_string(clang_sys::clang_CompileCommand_getDirectory(self.raw))
+        })
+    }
+    /// the main file being compiled
+    pub fn filename(&self) -> PathBuf {
+        PathBuf::from(unsafe {
+            cxstring_into_string(clang_sys::clang_CompileCommand_getFilename(self.raw))
+        })
+    }
+    /// all arguments, starting with the compiler executable
+    pub fn arguments(&self) -> Vec<String> {
+        (0..self.get_num_args()).map(|i| self.get_arg(i)).collect()
+    }
+    pub fn mapped_sources(&self) -> Vec<MappedSource> {
+        let n = unsafe { clang_sys::clang_CompileCommand_getNumMappedSources(self.raw) };
+        (0..n)
+            .map(|i| unsafe {
+                MappedSource {
+                    path: PathBuf::from(cxstring_into_string(
+                        clang_sys::clang_CompileCommand_getMappedSourcePath(self.raw, i),
+                    )),
+                    content: cxstring_into_string(
+                        clang_sys::clang_CompileCommand_getMappedSourceContent(self.raw, i),
+                    ),
+                }
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -137,6 +270,8 @@ mod test {
             _compile_db: PhantomData,
         };
         is_ffi_struct(&compile_commands);
+        assert_eq!(compile_commands.iter().len(), 0);
+        assert!((&compile_commands).into_iter().next().is_none());
 
         let compile_command = CompileCommand {
             raw: std::ptr::null_mut() as clang_sys::CXCompileCommand,
@@ -0,0 +1,297 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use super::{CompileCommand, JsonCompileCommand};
+use crate::error::{Error, Result};
+
+/// flags which only matter to the driver, they are dropped together with their value
+const DRIVER_FLAGS_WITH_VALUE: &[&str] = &["-o", "-MF", "-MT", "-MQ"];
+/// compiler flags which start like a driver flag with a joined value, e.g. `-objcmt-migrate-literals`
+const JOINED_LOOKALIKE_PREFIXES: &[&str] = &["-obj"];
+/// flags which only matter to the driver
+const DRIVER_FLAGS: &[&str] = &["-c", "-S", "-MD", "-MMD", "-MP", "-MG"];
+/// flags whose value is a directory, relative ones are resolved against the command directory
+const INCLUDE_FLAGS: &[&str] = &["-I", "-isystem", "-iquote", "-idirafter"];
+
+/// arguments of a compile command, prepared for parsing with [`Index::parse`](crate::index::Index::parse)
+///
+/// the compiler, the source file and driver-only flags like `-c` and `-o` are removed,
+/// relative include directories are made absolute
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CompileArgs {
+    directory: PathBuf,
+    file: PathBuf,
+    args: Vec<String>,
+}
+
+impl CompileArgs {
+    /// `arguments` start with the compiler, as in a compilation database
+    pub fn new<P: AsRef<Path>, Q: AsRef<Path>, S: AsRef<str>>(
+        directory: P,
+        file: Q,
+        arguments: &[S],
+    ) -> Self {
+        let directory = directory.as_ref().to_owned();
+        let file = directory.join(file);
+        let mut args = Vec::new();
+        let mut iter = arguments.iter().map(AsRef::as_ref).skip(1);
+        while let Some(arg) = iter.next() {
+            if DRIVER_FLAGS.contains(&arg) || directory.join(arg) == file {
+                continue;
+            }
+            if DRIVER_FLAGS_WITH_VALUE.contains(&arg) {
+                iter.next();
+                continue;
+            }
+            if is_joined_driver_flag(arg) {
+                continue;
+            }
+            if INCLUDE_FLAGS.contains(&arg) {
+                args.push(arg.to_owned());
+                if let Some(dir) = iter.next() {
+                    args.push(directory.join(dir).to_string_lossy().into_owned());
+                }
+                continue;
+            }
+            match INCLUDE_FLAGS.iter().find(|flag| arg.starts_with(*flag)) {
+                Some(flag) => args.push(format!(
+                    "{}{}",
+                    flag,
+                    directory.join(&arg[flag.len()..]).to_string_lossy()
+                )),
+                None => args.push(arg.to_owned()),
+            }
+        }
+        Self {
+            directory,
+            file,
+            args,
+        }
+    }
+
+    /// the working directory of the command
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+    /// the absolute path of the source file
+    pub fn file(&self) -> &Path {
+        &self.file
+    }
+    /// arguments without the source file, to be passed along with [`CompileArgs::file`]
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    pub fn push<S: Into<String>>(&mut self, arg: S) {
+        self.args.push(arg.into());
+    }
+    /// add `-Dname` or `-Dname=value`, replacing an existing definition of `name`
+    pub fn define(&mut self, name: &str, value: Option<&str>) {
+        self.remove_define(name);
+        self.args.push(match value {
+            Some(value) => format!("-D{}={}", name, value),
+            None => format!("-D{}", name),
+        });
+    }
+    /// remove all definitions of `name`
+    pub fn remove_define(&mut self, name: &str) {
+        let is_name = |define: &str| define.split('=').next() == Some(name);
+        let mut args = Vec::with_capacity(self.args.len());
+        let mut iter = std::mem::take(&mut self.args).into_iter();
+        while let Some(arg) = iter.next() {
+            if arg == "-D" {
+                match iter.next() {
+                    Some(define) if is_name(&define) => {}
+                    Some(define) => args.extend([arg, define]),
+                    None => args.push(arg),
+                }
+            } else if !arg.strip_prefix("-D").is_some_and(is_name) {
+                args.push(arg);
+            }
+        }
+        self.args = args;
+    }
+    /// add `-W<warning>`, e.g. `all` or `no-unused-variable`, replacing its opposite
+    pub fn add_warning(&mut self, warning: &str) {
+        let opposite = match warning.strip_prefix("no-") {
+            Some(warning) => warning.to_owned(),
+            None => format!("no-{}", warning),
+        };
+        self.remove_warning(&opposite);
+        self.remove_warning(warning);
+        self.args.push(format!("-W{}", warning));
+    }
+    /// remove `-W<warning>`
+    pub fn remove_warning(&mut self, warning: &str) {
+        let flag = format!("-W{}", warning);
+        self.args.retain(|arg| *arg != flag);
+    }
+    /// add the resource directory and system include directories `driver` uses,
+    /// which libclang cannot always find by itself
+    pub fn add_system_includes<P: AsRef<Path>>(&mut self, driver: P) -> Result<()> {
+        let driver = driver.as_ref();
+        let run = |args: &[&str]| {
+            let output = Command::new(driver)
+                .args(args)
+                .stdin(Stdio::null())
+                .output()
+                .map_err(|e| Error::Driver {
+                    driver: driver.to_owned(),
+                    message: e.to_string(),
+                })?;
+            if !output.status.success() {
+                return Err(Error::Driver {
+                    driver: driver.to_owned(),
+                    message: format!("{:?} exited with {}", args, output.status),
+                });
+            }
+            Ok(output)
+        };
+
+        let resource_dir = run(&["-print-resource-dir"])?;
+        let resource_dir = String::from_utf8_lossy(&resource_dir.stdout)
+            .trim()
+            .to_owned();
+        let language = match self.file.extension().and_then(|e| e.to_str()) {
+            Some("c") => "c",
+            _ => "c++",
+        };
+        let verbose = run(&["-E", "-x", language, "-", "-v"])?;
+        let includes = String::from_utf8_lossy(&verbose.stderr)
+            .lines()
+            .skip_while(|&line| line != "#include <...> search starts here:")
+            .skip(1)
+            .take_while(|&line| line != "End of search list.")
+            .map(|line| line.trim().to_owned())
+            .collect::<Vec<_>>();
+
+        if !resource_dir.is_empty() {
+            self.args.extend(["-resource-dir".to_owned(), resource_dir]);
+        }
+        for include in includes {
+            self.args.extend(["-isystem".to_owned(), include]);
+        }
+        Ok(())
+    }
+}
+
+impl From<&JsonCompileCommand> for CompileArgs {
+    fn from(command: &JsonCompileCommand) -> Self {
+        Self::new(&command.directory, &command.file, &command.arguments)
+    }
+}
+
+impl<'compile_commands> From<CompileCommand<'compile_commands>> for CompileArgs {
+    fn from(command: CompileCommand<'compile_commands>) -> Self {
+        Self::new(
+            command.directory(),
+            command.filename(),
+            &command.arguments(),
+        )
+    }
+}
+
+/// the joined form of a driver flag with a value, e.g. `-oa.o` or `-MFa.d`
+fn is_joined_driver_flag(arg: &str) -> bool {
+    DRIVER_FLAGS_WITH_VALUE
+        .iter()
+        .any(|flag| arg.starts_with(flag) && arg.len() > flag.len())
+        && !JOINED_LOOKALIKE_PREFIXES
+            .iter()
+            .any(|prefix| arg.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_value_struct(&CompileArgs::default());
+    }
+
+    #[test]
+    fn normalize() {
+        let args = CompileArgs::new(
+            "/build",
+            "../src/a.cpp",
+            &[
+                "/usr/bin/clang++",
+                "-Iinclude",
+                "-I",
+                "/usr/include/foo",
+                "-isystem",
+                "third_party",
+                "-iquote../src",
+                "-DNDEBUG",
+                "-MD",
+                "-MF",
+                "a.d",
+                "-o",
+                "a.o",
+                "-ob.o",
+                "-MFb.d",
+                "-objcmt-migrate-literals",
+                "-O2",
+                "-c",
+                "../src/a.cpp",
+            ],
+        );
+        assert_eq!(args.directory(), Path::new("/build"));
+        assert_eq!(args.file(), Path::new("/build/../src/a.cpp"));
+        assert_eq!(
+            args.args(),
+            [
+                "-I/build/include",
+                "-I",
+                "/usr/include/foo",
+                "-isystem",
+                "/build/third_party",
+                "-iquote/build/../src",
+                "-DNDEBUG",
+                "-objcmt-migrate-literals",
+                "-O2",
+            ]
+        );
+    }
+
+    #[test]
+    fn defines_and_warnings() {
+        let mut args = CompileArgs::new(
+            "/build",
+            "a.cpp",
+            &[
+                "cc",
+                "-DA",
+                "-D",
+                "B=1",
+                "-DAB",
+                "-Wall",
+                "-Wno-unused",
+                "a.cpp",
+            ],
+        );
+        args.define("B", Some("2"));
+        args.remove_define("A");
+        args.add_warning("unused");
+        args.add_warning("extra");
+        args.remove_warning("all");
+        args.push("-std=c++17");
+        assert_eq!(
+            args.args(),
+            ["-DAB", "-DB=2", "-Wunused", "-Wextra", "-std=c++17"]
+        );
+    }
+
+    #[test]
+    fn missing_driver() {
+        let mut args = CompileArgs::new("/build", "a.cpp", &["cc", "a.cpp"]);
+        assert!(matches!(
+            args.add_system_includes("does_not_exist"),
+            Err(Error::Driver { .. })
+        ));
+        assert!(args.args().is_empty());
+    }
+}
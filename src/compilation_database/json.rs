@@ -0,0 +1,353 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+
+use super::CompileCommand;
+use crate::error::{Error, Result};
+
+/// an entry of a JSON compilation database, independent of libclang
+///
+/// a `command` string is split into `arguments` the way a shell does when read,
+/// entries are always written with `arguments`
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "RawJsonCompileCommand")]
+pub struct JsonCompileCommand {
+    /// the working directory of the compilation, other paths are relative to it
+    pub directory: PathBuf,
+    /// the main source file
+    pub file: PathBuf,
+    /// all arguments, starting with the compiler executable
+    pub arguments: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+struct RawJsonCompileCommand {
+    directory: PathBuf,
+    file: PathBuf,
+    arguments: Option<Vec<String>>,
+    command: Option<String>,
+    output: Option<PathBuf>,
+}
+
+impl TryFrom<RawJsonCompileCommand> for JsonCompileCommand {
+    type Error = String;
+
+    fn try_from(raw: RawJsonCompileCommand) -> std::result::Result<Self, Self::Error> {
+        let arguments = match (raw.arguments, raw.command) {
+            (Some(arguments), _) => arguments,
+            (None, Some(command)) => shlex::split(&command)
+                .ok_or_else(|| format!("cannot split command {:?}", command))?,
+            (None, None) => return Err("either `arguments` or `command` is required".to_owned()),
+        };
+        Ok(Self {
+            directory: raw.directory,
+            file: raw.file,
+            arguments,
+            output: raw.output,
+        })
+    }
+}
+
+impl JsonCompileCommand {
+    /// `file` resolved against `directory`
+    pub fn absolute_file(&self) -> PathBuf {
+        self.directory.join(&self.file)
+    }
+}
+
+impl<'compile_commands> From<CompileCommand<'compile_commands>> for JsonCompileCommand {
+    fn from(command: CompileCommand<'compile_commands>) -> Self {
+        Self {
+            directory: command.directory(),
+            file: command.filename(),
+            arguments: command.arguments(),
+            output: None,
+        }
+    }
+}
+
+/// a JSON compilation database held in memory, which can be read, modified and written back
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JsonCompilationDatabase {
+    commands: Vec<JsonCompileCommand>,
+}
+
+impl JsonCompilationDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| Error::Io {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })?;
+        Self::from_json(&json, Some(path))
+    }
+    /// read `compile_commands.json` in `build_dir`
+    pub fn from_directory<P: AsRef<Path>>(build_dir: P) -> Result<Self> {
+        Self::from_file(build_dir.as_ref().join("compile_commands.json"))
+    }
+    fn from_json(json: &str, path: Option<&Path>) -> Result<Self> {
+        let error = |line, column, message| Error::ParseDatabase {
+            path: path.map(Path::to_owned),
+            line,
+            column,
+            message,
+        };
+        let entries: Vec<&RawValue> = serde_json::from_str(json)
+            .map_err(|e| error(e.line(), e.column(), json_error_message(&e)))?;
+        // entries are parsed one by one, so that errors found after an entry is read
+        // are reported at the entry rather than where the parser happens to be
+        let commands = entries
+            .into_iter()
+            .map(|entry| {
+                let entry = entry.get();
+                let offset = entry.as_ptr() as usize - json.as_ptr() as usize;
+                let preceding = &json[..offset];
+                let line = preceding.matches('\n').count() + 1;
+                let column = offset - preceding.rfind('\n').map_or(0, |i| i + 1) + 1;
+                let raw = serde_json::from_str::<RawJsonCompileCommand>(entry).map_err(|e| {
+                    let column = if e.line() == 1 {
+                        column + e.column() - 1
+                    } else {
+                        e.column()
+                    };
+                    error(line + e.line() - 1, column, json_error_message(&e))
+                })?;
+                JsonCompileCommand::try_from(raw).map_err(|message| error(line, column, message))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { commands })
+    }
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.commands)
+            .unwrap_or_else(|e| unreachable!("commands are always serializable, {}", e))
+    }
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_json()).map_err(|e| Error::Io {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })
+    }
+
+    pub fn commands(&self) -> &[JsonCompileCommand] {
+        &self.commands
+    }
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, JsonCompileCommand> {
+        self.commands.iter()
+    }
+    /// commands whose [`JsonCompileCommand::absolute_file`] is `file`
+    pub fn commands_for<P: AsRef<Path>>(
+        &self,
+        file: P,
+    ) -> impl Iterator<Item = &JsonCompileCommand> + '_ {
+        let file = file.as_ref().to_owned();
+        self.commands
+            .iter()
+            .filter(move |command| command.absolute_file() == file)
+    }
+    pub fn push(&mut self, command: JsonCompileCommand) {
+        self.commands.push(command);
+    }
+    pub fn retain<F: FnMut(&JsonCompileCommand) -> bool>(&mut self, f: F) {
+        self.commands.retain(f);
+    }
+    /// add all commands of `other`, they replace existing commands for the same file
+    pub fn merge(&mut self, other: JsonCompilationDatabase) {
+        self.commands.retain(|command| {
+            let file = command.absolute_file();
+            !other
+                .commands
+                .iter()
+                .any(|other| other.absolute_file() == file)
+        });
+        self.commands.extend(other.commands);
+    }
+}
+
+/// the message of `e` without the location, which is reported separately
+fn json_error_message(e: &serde_json::Error) -> String {
+    let mut message = e.to_string();
+    if let Some(i) = message.rfind(" at line ") {
+        message.truncate(i);
+    }
+    message
+}
+
+impl FromStr for JsonCompilationDatabase {
+    type Err = Error;
+
+    fn from_str(json: &str) -> Result<Self> {
+        Self::from_json(json, None)
+    }
+}
+
+impl FromIterator<JsonCompileCommand> for JsonCompilationDatabase {
+    fn from_iter<I: IntoIterator<Item = JsonCompileCommand>>(iter: I) -> Self {
+        Self {
+            commands: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<JsonCompileCommand> for JsonCompilationDatabase {
+    fn extend<I: IntoIterator<Item = JsonCompileCommand>>(&mut self, iter: I) {
+        self.commands.extend(iter);
+    }
+}
+
+impl IntoIterator for JsonCompilationDatabase {
+    type Item = JsonCompileCommand;
+    type IntoIter = std::vec::IntoIter<JsonCompileCommand>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.commands.into_iter()
+    }
+}
+
+impl<'db> IntoIterator for &'db JsonCompilationDatabase {
+    type Item = &'db JsonCompileCommand;
+    type IntoIter = std::slice::Iter<'db, JsonCompileCommand>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.commands.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn command(file: &str, arguments: &[&str]) -> JsonCompileCommand {
+        JsonCompileCommand {
+            directory: PathBuf::from("/build"),
+            file: PathBuf::from(file),
+            arguments: arguments.iter().map(|&s| s.to_owned()).collect(),
+            output: None,
+        }
+    }
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_value_struct(&JsonCompileCommand::default());
+        is_value_struct(&JsonCompilationDatabase::new());
+    }
+
+    #[test]
+    fn read_command_and_arguments() {
+        let db = r#"[
+  {
+    "directory": "/build",
+    "file": "a.cpp",
+    "command": "clang++ -DNAME=\"a b\" -c 'a.cpp'",
+    "output": "a.o"
+  },
+  {
+    "directory": "/build",
+    "file": "/src/b.cpp",
+    "arguments": ["clang++", "-c", "/src/b.cpp"]
+  }
+]"#
+        .parse::<JsonCompilationDatabase>()
+        .unwrap();
+
+        let mut a = command("a.cpp", &["clang++", "-DNAME=a b", "-c", "a.cpp"]);
+        a.output = Some(PathBuf::from("a.o"));
+        let b = command("/src/b.cpp", &["clang++", "-c", "/src/b.cpp"]);
+        assert_eq!(db.commands(), [a, b]);
+        assert_eq!(db.commands_for("/build/a.cpp").count(), 1);
+        assert_eq!(db.commands_for("/src/b.cpp").count(), 1);
+        assert_eq!(db.commands_for("/build/b.cpp").count(), 0);
+    }
+
+    #[test]
+    fn write_and_read_back() {
+        let mut db = JsonCompilationDatabase::new();
+        db.push(command("a.cpp", &["clang++", "-c", "a.cpp"]));
+        db.extend([command("b.cpp", &["clang++", "-c", "b.cpp"])]);
+        let read_back = db.to_json().parse::<JsonCompilationDatabase>().unwrap();
+        assert_eq!(read_back, db);
+        assert!(!db.to_json().contains("output"));
+    }
+
+    #[test]
+    fn merge_and_retain() {
+        let mut db = [
+            command("a.cpp", &["clang++", "-c", "a.cpp"]),
+            command("b.cpp", &["clang++", "-c", "b.cpp"]),
+        ]
+        .into_iter()
+        .collect::<JsonCompilationDatabase>();
+        let other = [
+            command("b.cpp", &["clang++", "-O2", "-c", "b.cpp"]),
+            command("c.cpp", &["clang++", "-c", "c.cpp"]),
+        ]
+        .into_iter()
+        .collect::<JsonCompilationDatabase>();
+        db.merge(other);
+        let arguments = db
+            .iter()
+            .map(|command| command.arguments.join(" "))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            arguments,
+            [
+                "clang++ -c a.cpp",
+                "clang++ -O2 -c b.cpp",
+                "clang++ -c c.cpp"
+            ]
+        );
+
+        db.retain(|command| command.file != Path::new("a.cpp"));
+        assert_eq!(db.len(), 2);
+    }
+
+    #[test]
+    fn parse_errors_have_location() {
+        let error = r#"[
+  {"directory": "/build", "file": "a.cpp"},
+  {"directory": "/build", "file": "b.cpp", "command": "clang++ b.cpp"}
+]"#
+        .parse::<JsonCompilationDatabase>()
+        .unwrap_err();
+        assert_eq!(
+            error,
+            Error::ParseDatabase {
+                path: None,
+                line: 2,
+                column: 3,
+                message: "either `arguments` or `command` is required".to_owned(),
+            }
+        );
+
+        let error = "[\n  {\"directory\": \"/build\",\n   \"file\": 1}\n]"
+            .parse::<JsonCompilationDatabase>()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            Error::ParseDatabase {
+                path: None,
+                line: 3,
+                column: 12,
+                message: "invalid type: integer `1`, expected path string".to_owned(),
+            }
+        );
+
+        let error = JsonCompilationDatabase::from_directory("does_not_exist").unwrap_err();
+        assert!(matches!(error, Error::Io { .. }));
+    }
+}
@@ -0,0 +1,176 @@
+use std::path::PathBuf;
+
+use crate::compilation_database::CompilationDatabaseError;
+use crate::index::{ParseError, SaveError};
+
+/// errors of all fallible operations of this crate
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Error {
+    /// libclang cannot be loaded, with the message from `clang-sys`
+    LoadLibrary(String),
+    /// a path is not valid UTF-8, or contains a nul byte
+    InvalidPath(PathBuf),
+    /// a string passed to libclang contains a nul byte
+    InvalidString(String),
+    /// libclang failed to create an index
+    CreateIndex,
+    /// libclang failed to create an index action
+    CreateIndexAction,
+    /// parsing a translation unit failed
+    Parse(ParseError),
+    /// reparsing a translation unit failed, the translation unit is disposed
+    Reparse(ParseError),
+    /// reading a translation unit from an AST file failed
+    AstRead { path: PathBuf, error: ParseError },
+    /// saving a translation unit to an AST file failed
+    Save { path: PathBuf, error: SaveError },
+    /// a compile command index is out of range
+    CommandIndex { index: u32, len: u32 },
+    /// loading a compilation database failed
+    LoadDatabase {
+        build_dir: PathBuf,
+        error: CompilationDatabaseError,
+    },
+    /// indexing a source file or a translation unit failed
+    Index(ParseError),
+    /// code completion failed, `line` and `column` are 1-based
+    CodeComplete {
+        path: PathBuf,
+        line: u32,
+        column: u32,
+    },
+    /// reading or writing a file failed, with the message of the I/O error
+    Io { path: PathBuf, message: String },
+    /// running a compiler driver failed
+    Driver { driver: PathBuf, message: String },
+    /// a JSON compilation database is malformed, `line` and `column` are 1-based
+    ParseDatabase {
+        path: Option<PathBuf>,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LoadLibrary(message) => write!(f, "failed to load libclang, {}", message),
+            Self::InvalidPath(path) => write!(f, "invalid path {:?}", path),
+            Self::InvalidString(s) => write!(f, "string {:?} contains a nul byte", s),
+            Self::CreateIndex => write!(f, "failed to create index"),
+            Self::CreateIndexAction => write!(f, "failed to create index action"),
+            Self::Parse(error) => write!(f, "failed to parse translation unit, {:?}", error),
+            Self::Reparse(error) => write!(f, "failed to reparse translation unit, {:?}", error),
+            Self::AstRead { path, error } => {
+                write!(f, "failed to read AST file {:?}, {:?}", path, error)
+            }
+            Self::Save { path, error } => {
+                write!(f, "failed to save AST file {:?}, {:?}", path, error)
+            }
+            Self::CommandIndex { index, len } => write!(
+                f,
+                "compile command index {} is out of range for {} commands",
+                index, len
+            ),
+            Self::LoadDatabase { build_dir, error } => write!(
+                f,
+                "failed to load compilation database from {:?}, {:?}",
+                build_dir, error
+            ),
+            Self::Index(error) => write!(f, "failed to index translation unit, {:?}", error),
+            Self::CodeComplete { path, line, column } => write!(
+                f,
+                "failed to complete code in {:?} at line {} column {}",
+                path, line, column
+            ),
+            Self::Io { path, message } => write!(f, "failed to access {:?}, {}", path, message),
+            Self::Driver { driver, message } => {
+                write!(f, "failed to run driver {:?}, {}", driver, message)
+            }
+            Self::ParseDatabase {
+                path,
+                line,
+                column,
+                message,
+            } => {
+                let path = path.as_deref().unwrap_or_else(|| "<string>".as_ref());
+                write!(
+                    f,
+                    "failed to parse compilation database {:?} at line {} column {}, {}",
+                    path, line, column, message
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        fn is_error<T: std::error::Error + Send + Sync + Clone + Eq + std::hash::Hash>(_: &T) {}
+
+        is_error(&Error::InvalidPath(PathBuf::from("a.cpp")));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Error::InvalidString("a\0b".to_owned()).to_string(),
+            r#"string "a\0b" contains a nul byte"#
+        );
+        assert_eq!(Error::CreateIndex.to_string(), "failed to create index");
+        assert_eq!(
+            Error::CommandIndex { index: 3, len: 2 }.to_string(),
+            "compile command index 3 is out of range for 2 commands"
+        );
+        assert_eq!(
+            Error::from(ParseError::Crashed).to_string(),
+            "failed to parse translation unit, Crashed"
+        );
+        assert_eq!(
+            Error::LoadDatabase {
+                build_dir: PathBuf::from("build"),
+                error: CompilationDatabaseError::CanNotLoadDatabase,
+            }
+            .to_string(),
+            r#"failed to load compilation database from "build", CanNotLoadDatabase"#
+        );
+        assert_eq!(
+            Error::Index(ParseError::Failure).to_string(),
+            "failed to index translation unit, Failure"
+        );
+        assert_eq!(
+            Error::CodeComplete {
+                path: PathBuf::from("a.cpp"),
+                line: 2,
+                column: 5,
+            }
+            .to_string(),
+            r#"failed to complete code in "a.cpp" at line 2 column 5"#
+        );
+        assert_eq!(
+            Error::ParseDatabase {
+                path: Some(PathBuf::from("compile_commands.json")),
+                line: 3,
+                column: 7,
+                message: "expected `,` or `}`".to_owned(),
+            }
+            .to_string(),
+            r#"failed to parse compilation database "compile_commands.json" at line 3 column 7, expected `,` or `}`"#
+        );
+    }
+}
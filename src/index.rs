@@ -1,10 +1,54 @@
+use std::cmp::Ordering;
+use std::ffi::{CStr, CString};
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::path::Path;
 
 use crate::clang::Clang;
-//use crate::compilation_database::CompileCommand;
-use crate::utility::{cxstring_into_string, path_to_cstring};
+use crate::compilation_database::{
+    CompileArgs, CompileCommand, CompileCommands, CompileCommandsIter, JsonCompileCommand,
+};
+use crate::error::{Error, Result};
+use crate::utility::{
+    cxstring_into_string, define_flags, path_to_cstring, CStringArray, CaughtPanic,
+};
+
+mod comment;
+mod completion;
+mod cursor_kind;
+mod diagnostic;
+mod evaluate;
+mod file;
+mod find;
+mod inclusion;
+mod indexing;
+mod printing_policy;
+mod token;
+mod traversal;
+mod types;
+
+pub use comment::{BlockComment, Comment, InlineCommandRenderKind, InlineComment, ParamDirection};
+pub use completion::{
+    Availability, CodeCompleteOptions, CompletionChunk, CompletionChunkKind, CompletionContext,
+    CompletionResult, CompletionResults, CompletionString,
+};
+pub use cursor_kind::CursorKind;
+pub use diagnostic::{
+    Diagnostic, DiagnosticDisplayOptions, DiagnosticOption, DiagnosticSeverity, Diagnostics, FixIt,
+};
+pub use evaluate::{EvalResult, IntValue};
+pub use file::{File, FileUniqueId};
+pub use find::{FindResult, Visit};
+pub use inclusion::{IncludeGraph, Inclusion};
+pub use indexing::{
+    DeclInfo, EntityInfo, EntityKind, EntityLanguage, EntityRefInfo, EntityRefKind,
+    EntityTemplateKind, ImportedAstFileInfo, IncludedFileInfo, IndexAction, IndexOptions,
+    IndexerCallbacks, SymbolRoles,
+};
+pub use printing_policy::PrintingPolicy;
+pub use token::{Token, TokenKind, Tokens};
+pub use traversal::{Ancestors, BreadthFirstDescendants, Descendants};
+pub use types::{Type, TypeKind, TypeLayoutError};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum ExcludePCH {
@@ -31,33 +75,63 @@ impl<'clang> Drop for Index<'clang> {
 }
 
 impl<'clang> Index<'clang> {
-    fn new(exclude: ExcludePCH, display: DisplayDiagnostics) -> Self {
+    fn try_new(exclude: ExcludePCH, display: DisplayDiagnostics) -> Result<Self> {
         let raw = unsafe {
             clang_sys::clang_createIndex(
                 i32::from(exclude == ExcludePCH::On),
                 i32::from(display == DisplayDiagnostics::On),
             )
         };
-        assert!(!raw.is_null());
-        Self {
+        if raw.is_null() {
+            return Err(Error::CreateIndex);
+        }
+        Ok(Self {
             raw,
             _clang: PhantomData,
-        }
+        })
     }
 }
 
 impl Clang {
+    /// # Panics
+    ///
+    /// it panics if libclang fails to create the index, see [`Clang::try_create_index_with_display_diagnostics`]
     pub fn create_index_with_display_diagnostics(&self) -> Index<'_> {
-        Index::new(ExcludePCH::Off, DisplayDiagnostics::On)
+        self.try_create_index_with_display_diagnostics()
+            .unwrap_or_else(|e| panic!("{}", e))
     }
+    pub fn try_create_index_with_display_diagnostics(&self) -> Result<Index<'_>> {
+        Index::try_new(ExcludePCH::Off, DisplayDiagnostics::On)
+    }
+    /// # Panics
+    ///
+    /// it panics if libclang fails to create the index, see [`Clang::try_create_index_with_exclude_pch`]
     pub fn create_index_with_exclude_pch(&self) -> Index<'_> {
-        Index::new(ExcludePCH::On, DisplayDiagnostics::Off)
+        self.try_create_index_with_exclude_pch()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_create_index_with_exclude_pch(&self) -> Result<Index<'_>> {
+        Index::try_new(ExcludePCH::On, DisplayDiagnostics::Off)
     }
+    /// # Panics
+    ///
+    /// it panics if libclang fails to create the index,
+    /// see [`Clang::try_create_index_with_exclude_pch_and_display_diagnostics`]
     pub fn create_index_with_exclude_pch_and_display_diagnostics(&self) -> Index<'_> {
-        Index::new(ExcludePCH::On, DisplayDiagnostics::On)
+        self.try_create_index_with_exclude_pch_and_display_diagnostics()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_create_index_with_exclude_pch_and_display_diagnostics(&self) -> Result<Index<'_>> {
+        Index::try_new(ExcludePCH::On, DisplayDiagnostics::On)
     }
+    /// # Panics
+    ///
+    /// it panics if libclang fails to create the index, see [`Clang::try_create_index`]
     pub fn create_index(&self) -> Index<'_> {
-        Index::new(ExcludePCH::Off, DisplayDiagnostics::Off)
+        self.try_create_index().unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_create_index(&self) -> Result<Index<'_>> {
+        Index::try_new(ExcludePCH::Off, DisplayDiagnostics::Off)
     }
 }
 
@@ -82,85 +156,323 @@ impl<'index> TranslationUnit<'index> {
     }
 }
 
+/// a file whose contents are given in memory rather than read from disk, as `(path, contents)`
+pub type UnsavedFile<'a> = (&'a Path, &'a str);
+
+/// keeps filenames alive as long as the `CXUnsavedFile`s pointing to them
+#[derive(Debug)]
+struct RawUnsavedFiles {
+    _filenames: Vec<CString>,
+    raw: Vec<clang_sys::CXUnsavedFile>,
+}
+
+impl RawUnsavedFiles {
+    fn new(unsaved_files: &[UnsavedFile]) -> Result<Self> {
+        let filenames = unsaved_files
+            .iter()
+            .map(|(path, _)| path_to_cstring(path))
+            .collect::<Result<Vec<_>>>()?;
+        let raw = unsaved_files
+            .iter()
+            .zip(&filenames)
+            .map(|((_, contents), filename)| clang_sys::CXUnsavedFile {
+                Filename: filename.as_ptr(),
+                Contents: contents.as_ptr() as *const std::os::raw::c_char,
+                Length: contents.len() as std::os::raw::c_ulong,
+            })
+            .collect();
+        Ok(Self {
+            _filenames: filenames,
+            raw,
+        })
+    }
+    fn as_mut_ptr(&mut self) -> *mut clang_sys::CXUnsavedFile {
+        self.raw.as_mut_ptr()
+    }
+    fn len(&self) -> u32 {
+        self.raw.len() as u32
+    }
+}
+
+define_flags! {
+    /// options for parsing a translation unit, mirrors `CXTranslationUnit_Flags`
+    pub struct ParseOptions: clang_sys::CXTranslationUnit_Flags {
+        /// keep macro definitions and expansions, and inclusion directives in the AST
+        const DETAILED_PREPROCESSING_RECORD = clang_sys::CXTranslationUnit_DetailedPreprocessingRecord;
+        /// the translation unit is incomplete, e.g. a header or a precompiled header
+        const INCOMPLETE = clang_sys::CXTranslationUnit_Incomplete;
+        /// build a precompiled preamble, speeding up reparsing
+        const PRECOMPILED_PREAMBLE = clang_sys::CXTranslationUnit_PrecompiledPreamble;
+        /// cache code completion results, speeding up code completion
+        const CACHE_COMPLETION_RESULTS = clang_sys::CXTranslationUnit_CacheCompletionResults;
+        /// the translation unit will be serialized with `TranslationUnit::save`
+        const FOR_SERIALIZATION = clang_sys::CXTranslationUnit_ForSerialization;
+        /// deprecated, enables chained precompiled headers
+        const CXX_CHAINED_PCH = clang_sys::CXTranslationUnit_CXXChainedPCH;
+        /// skip bodies of all functions and methods
+        const SKIP_FUNCTION_BODIES = clang_sys::CXTranslationUnit_SkipFunctionBodies;
+        /// include brief documentation comments in code completion results
+        const INCLUDE_BRIEF_COMMENTS_IN_CODE_COMPLETION = clang_sys::CXTranslationUnit_IncludeBriefCommentsInCodeCompletion;
+        /// build the precompiled preamble at the first parse, instead of the first reparse
+        const CREATE_PREAMBLE_ON_FIRST_PARSE = clang_sys::CXTranslationUnit_CreatePreambleOnFirstParse;
+        /// keep going after fatal errors, e.g. a missing header
+        const KEEP_GOING = clang_sys::CXTranslationUnit_KeepGoing;
+        /// parse the main file only, without following `#include`s
+        const SINGLE_FILE_PARSE = clang_sys::CXTranslationUnit_SingleFileParse;
+        /// apply `SKIP_FUNCTION_BODIES` to the preamble only
+        const LIMIT_SKIP_FUNCTION_BODIES_TO_PREAMBLE = clang_sys::CXTranslationUnit_LimitSkipFunctionBodiesToPreamble;
+        /// keep attributed types, e.g. `_Nonnull`, instead of their modified types
+        const INCLUDE_ATTRIBUTED_TYPES = clang_sys::CXTranslationUnit_IncludeAttributedTypes;
+        /// visit implicit attributes, e.g. `visibility` from `#pragma`
+        const VISIT_IMPLICIT_ATTRIBUTES = clang_sys::CXTranslationUnit_VisitImplicitAttributes;
+        /// report only errors from included files, warnings are dropped
+        const IGNORE_NON_ERRORS_FROM_INCLUDED_FILES = clang_sys::CXTranslationUnit_IgnoreNonErrorsFromIncludedFiles;
+        /// keep blocks excluded by `#if`, `#ifdef` and so on in the preprocessing record
+        const RETAIN_EXCLUDED_CONDITIONAL_BLOCKS = clang_sys::CXTranslationUnit_RetainExcludedConditionalBlocks;
+    }
+}
+
+impl ParseOptions {
+    /// options suited for a translation unit being edited, e.g. in an IDE
+    pub fn editing_default() -> Self {
+        Self::from_raw(unsafe { clang_sys::clang_defaultEditingTranslationUnitOptions() } as _)
+    }
+}
+
+/// reasons parsing a translation unit can fail, mirrors `CXErrorCode`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ParseError {
+    Failure,
+    Crashed,
+    InvalidArguments,
+    AstReadError,
+}
+
+impl ParseError {
+    fn from_error_code(code: clang_sys::CXErrorCode) -> std::result::Result<(), Self> {
+        match code {
+            clang_sys::CXError_Success => Ok(()),
+            clang_sys::CXError_Failure => Err(Self::Failure),
+            clang_sys::CXError_Crashed => Err(Self::Crashed),
+            clang_sys::CXError_InvalidArguments => Err(Self::InvalidArguments),
+            clang_sys::CXError_ASTReadError => Err(Self::AstReadError),
+            e => unreachable!("unexpected CXErrorCode {}", e),
+        }
+    }
+}
+
+/// reasons saving a translation unit can fail, mirrors `CXSaveError`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SaveError {
+    /// e.g. the file cannot be written
+    Unknown,
+    /// the translation unit has errors, which prevent it from being saved
+    TranslationErrors,
+    InvalidTranslationUnit,
+}
+
+impl SaveError {
+    fn from_raw(raw: clang_sys::CXSaveError) -> std::result::Result<(), Self> {
+        match raw {
+            clang_sys::CXSaveError_None => Ok(()),
+            clang_sys::CXSaveError_Unknown => Err(Self::Unknown),
+            clang_sys::CXSaveError_TranslationErrors => Err(Self::TranslationErrors),
+            clang_sys::CXSaveError_InvalidTU => Err(Self::InvalidTranslationUnit),
+            e => unreachable!("unexpected CXSaveError {}", e),
+        }
+    }
+}
+
 impl<'index> Index<'index> {
+    fn parse_raw(
+        &self,
+        path: Option<&CStr>,
+        args: &CStringArray,
+        unsaved_files: &mut RawUnsavedFiles,
+        options: ParseOptions,
+    ) -> Result<TranslationUnit<'_>> {
+        let mut raw = std::ptr::null_mut();
+        let code = unsafe {
+            clang_sys::clang_parseTranslationUnit2(
+                self.raw,
+                path.map_or(std::ptr::null(), CStr::as_ptr),
+                args.as_ptr(),
+                args.len() as i32,
+                unsaved_files.as_mut_ptr(),
+                unsaved_files.len(),
+                options.bits(),
+                &mut raw,
+            )
+        };
+        ParseError::from_error_code(code)?;
+        if raw.is_null() {
+            return Err(Error::Parse(ParseError::Failure));
+        }
+        Ok(TranslationUnit::from_raw(raw))
+    }
+    /// parse `path` with compiler `args`, which should not include `path` itself
+    ///
+    /// `unsaved_files` replace contents of files on disk, or provide files that do not exist at all
+    pub fn parse<P: AsRef<Path>, S: AsRef<str>>(
+        &self,
+        path: P,
+        args: &[S],
+        unsaved_files: &[UnsavedFile],
+        options: ParseOptions,
+    ) -> Result<TranslationUnit<'_>> {
+        let path = path_to_cstring(path)?;
+        let args = CStringArray::new(args)?;
+        let mut unsaved_files = RawUnsavedFiles::new(unsaved_files)?;
+        self.parse_raw(Some(&path), &args, &mut unsaved_files, options)
+    }
+    /// # Panics
+    ///
+    /// it panics if the AST file cannot be read, see [`Index::try_create_translation_unit`]
     pub fn create_translation_unit<P: AsRef<Path>>(&self, ast_filename: P) -> TranslationUnit<'_> {
-        let ast_filename = path_to_cstring(ast_filename);
-        let raw =
-            unsafe { clang_sys::clang_createTranslationUnit(self.raw, ast_filename.as_ptr()) };
-        assert!(!raw.is_null());
-        TranslationUnit::from_raw(raw)
+        self.try_create_translation_unit(ast_filename)
+            .unwrap_or_else(|e| panic!("{}", e))
     }
+    pub fn try_create_translation_unit<P: AsRef<Path>>(
+        &self,
+        ast_filename: P,
+    ) -> Result<TranslationUnit<'_>> {
+        let filename = path_to_cstring(&ast_filename)?;
+        let mut raw = std::ptr::null_mut();
+        let code = unsafe {
+            clang_sys::clang_createTranslationUnit2(self.raw, filename.as_ptr(), &mut raw)
+        };
+        let ast_read_error = |error| Error::AstRead {
+            path: ast_filename.as_ref().to_owned(),
+            error,
+        };
+        ParseError::from_error_code(code).map_err(ast_read_error)?;
+        if raw.is_null() {
+            return Err(ast_read_error(ParseError::Failure));
+        }
+        Ok(TranslationUnit::from_raw(raw))
+    }
+    /// # Panics
+    ///
+    /// it panics if parsing fails, see [`Index::try_parse_translation_unit_from_compile_command`]
     pub fn parse_translation_unit_from_compile_command(
         &self,
-        compile_command: crate::compilation_database::CompileCommand,
+        compile_command: CompileCommand,
+        options: ParseOptions,
     ) -> TranslationUnit<'_> {
-        let raw = unsafe {
-            let num_args = compile_command.get_num_args();
-            let mut arguments = Vec::with_capacity(num_args as usize);
-            let mut args = Vec::with_capacity(num_args as usize);
-            for i in 0..num_args {
-                let arg = clang_sys::clang_CompileCommand_getArg(compile_command.raw, i);
-                args.push(arg);
-                let arg = clang_sys::clang_getCString(arg);
-                arguments.push(arg)
-            }
-            let raw = clang_sys::clang_parseTranslationUnit(
-                self.raw,
-                std::ptr::null(),
-                arguments.as_ptr(),
-                num_args as i32,
-                std::ptr::null_mut(),
-                0,
-                clang_sys::CXTranslationUnit_None,
-            );
-            assert!(!raw.is_null());
-            args.iter().for_each(|e| clang_sys::clang_disposeString(*e));
-            raw
-        };
-        assert!(!raw.is_null());
-        TranslationUnit::from_raw(raw)
+        self.try_parse_translation_unit_from_compile_command(compile_command, options)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_parse_translation_unit_from_compile_command(
+        &self,
+        compile_command: CompileCommand,
+        options: ParseOptions,
+    ) -> Result<TranslationUnit<'_>> {
+        let args = CStringArray::new(&compile_command.arguments())?;
+        let mut unsaved_files = RawUnsavedFiles::new(&[])?;
+        self.parse_raw(None, &args, &mut unsaved_files, options)
+    }
+    /// parse a command of a [`JsonCompilationDatabase`](crate::compilation_database::JsonCompilationDatabase)
+    ///
+    /// relative paths are resolved against the directory of the command, see [`CompileArgs`]
+    pub fn parse_json_compile_command(
+        &self,
+        compile_command: &JsonCompileCommand,
+        options: ParseOptions,
+    ) -> Result<TranslationUnit<'_>> {
+        let args = CompileArgs::from(compile_command);
+        self.parse(args.file(), args.args(), &[], options)
+    }
+    /// parse each of `compile_commands` lazily, one translation unit at a time
+    ///
+    /// a failing command does not stop the iteration, its error is yielded along with it
+    ///
+    /// relative paths are resolved against the directory of each command, see [`CompileArgs`]
+    ///
+    /// it takes the commands of
+    /// [`CompilationDatabase::all_compile_commands`](crate::compilation_database::CompilationDatabase::all_compile_commands)
+    /// rather than the database itself, so that the yielded [`CompileCommand`]s can borrow from them
+    pub fn parse_all<'compile_commands>(
+        &self,
+        compile_commands: &'compile_commands CompileCommands<'compile_commands>,
+        options: ParseOptions,
+    ) -> ParseAll<'_, 'compile_commands> {
+        ParseAll {
+            index: self,
+            commands: compile_commands.iter(),
+            options,
+        }
     }
 }
 
+/// iterator over parsed compile commands, see [`Index::parse_all`]
 #[derive(Debug)]
+pub struct ParseAll<'index, 'compile_commands> {
+    index: &'index Index<'index>,
+    commands: CompileCommandsIter<'compile_commands>,
+    options: ParseOptions,
+}
+
+impl<'index, 'compile_commands> Iterator for ParseAll<'index, 'compile_commands> {
+    type Item = (
+        CompileCommand<'compile_commands>,
+        Result<TranslationUnit<'index>>,
+    );
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let command = self.commands.next()?;
+        let args = CompileArgs::from(command);
+        let tu = self
+            .index
+            .parse(args.file(), args.args(), &[], self.options);
+        Some((command, tu))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.commands.size_hint()
+    }
+}
+
+impl<'index, 'compile_commands> ExactSizeIterator for ParseAll<'index, 'compile_commands> {}
+
+#[derive(Debug, Clone, Copy)]
 pub struct Cursor<'tu> {
     raw: clang_sys::CXCursor,
     _tu: PhantomData<&'tu TranslationUnit<'tu>>,
 }
 
-pub type Payload = *const std::ffi::c_void;
-pub fn to_payload<T>(v: &T) -> Payload {
-    v as *const _ as Payload
+impl<'tu> PartialEq for Cursor<'tu> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { clang_sys::clang_equalCursors(self.raw, other.raw) != 0 }
+    }
 }
 
-/// convert payload to its original type
-///
-/// # Safety
-///
-/// It is undefined behavior if the wrong type `T` is given
-///
-/// # Example
-///
-/// ```
-/// use clang_rs_binding::index::{to_payload, from_payload};
-///
-/// let i = 42_i32;
-/// let payload = to_payload(&i);
-/// let j = unsafe { &*from_payload(payload) };
-/// assert_eq!(&i as *const _, j as *const _);
-/// assert_eq!(i, *j);
-/// ```
-pub unsafe fn from_payload<'a, T>(payload: Payload) -> &'a T {
-    &*(payload as *const T)
+impl<'tu> Eq for Cursor<'tu> {}
+
+impl<'tu> Hash for Cursor<'tu> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        unsafe { clang_sys::clang_hashCursor(self.raw) }.hash(state);
+    }
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ChildVisitResult {}
+/// what to do after visiting a child, mirrors `CXChildVisitResult`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChildVisit {
+    /// stop the traversal
+    Break,
+    /// continue with the next sibling, without visiting children of the current cursor
+    Continue,
+    /// visit children of the current cursor, then its siblings
+    Recurse,
+}
 
-impl ChildVisitResult {
-    pub const BREAK: clang_sys::CXChildVisitResult = clang_sys::CXChildVisit_Break;
-    pub const CONTINUE: clang_sys::CXChildVisitResult = clang_sys::CXChildVisit_Continue;
-    pub const RECURSIVE: clang_sys::CXChildVisitResult = clang_sys::CXChildVisit_Recurse;
+impl ChildVisit {
+    fn to_raw(self) -> clang_sys::CXChildVisitResult {
+        match self {
+            Self::Break => clang_sys::CXChildVisit_Break,
+            Self::Continue => clang_sys::CXChildVisit_Continue,
+            Self::Recurse => clang_sys::CXChildVisit_Recurse,
+        }
+    }
 }
 
 impl<'tu> Cursor<'tu> {
@@ -171,79 +483,153 @@ impl<'tu> Cursor<'tu> {
             _tu: PhantomData,
         }
     }
+    /// `None` if `raw` is a null cursor or of an invalid kind
+    fn from_raw_valid(raw: clang_sys::CXCursor) -> Option<Self> {
+        let is_null = unsafe { clang_sys::clang_Cursor_isNull(raw) } != 0;
+        (!is_null && !CursorKind::from_raw(raw.kind).is_invalid()).then(|| Self::from_raw(raw))
+    }
 
+    pub fn kind(&self) -> CursorKind {
+        CursorKind::from_raw(unsafe { clang_sys::clang_getCursorKind(self.raw) })
+    }
     pub fn kind_spelling(&self) -> String {
-        unsafe {
-            let kind = clang_sys::clang_getCursorKind(self.raw);
-            cxstring_into_string(clang_sys::clang_getCursorKindSpelling(kind))
-        }
+        self.kind().spelling()
     }
     pub fn spelling(&self) -> String {
         unsafe { cxstring_into_string(clang_sys::clang_getCursorSpelling(self.raw)) }
     }
+    fn tu(&self) -> clang_sys::CXTranslationUnit {
+        unsafe { clang_sys::clang_Cursor_getTranslationUnit(self.raw) }
+    }
+    pub fn location(&self) -> SourceLocation<'_> {
+        SourceLocation::from_raw(
+            unsafe { clang_sys::clang_getCursorLocation(self.raw) },
+            self.tu(),
+        )
+    }
     pub fn is_from_main_file(&self) -> bool {
-        unsafe {
-            let location = clang_sys::clang_getCursorLocation(self.raw);
-            clang_sys::clang_Location_isFromMainFile(location) == 0
-        }
+        self.location().is_from_main_file()
     }
-    pub fn is_function_decl(&self) -> bool {
-        unsafe { clang_sys::CXCursor_FunctionDecl == clang_sys::clang_getCursorKind(self.raw) }
+    pub fn is_declaration(&self) -> bool {
+        self.kind().is_declaration()
     }
-    pub fn is_cxx_method(&self) -> bool {
-        unsafe { clang_sys::CXCursor_CXXMethod == clang_sys::clang_getCursorKind(self.raw) }
+    pub fn is_reference(&self) -> bool {
+        self.kind().is_reference()
     }
-    pub fn is_function_template(&self) -> bool {
-        unsafe { clang_sys::CXCursor_FunctionTemplate == clang_sys::clang_getCursorKind(self.raw) }
+    pub fn is_expression(&self) -> bool {
+        self.kind().is_expression()
     }
-    pub fn extent(&self) -> SourceRange {
-        SourceRange {
-            raw: unsafe { clang_sys::clang_getCursorExtent(self.raw) },
-            _cursor: PhantomData,
-        }
+    pub fn is_statement(&self) -> bool {
+        self.kind().is_statement()
+    }
+    pub fn is_attribute(&self) -> bool {
+        self.kind().is_attribute()
+    }
+    pub fn is_invalid(&self) -> bool {
+        self.kind().is_invalid()
+    }
+    pub fn is_translation_unit(&self) -> bool {
+        self.kind().is_translation_unit()
+    }
+    pub fn is_preprocessing(&self) -> bool {
+        self.kind().is_preprocessing()
     }
-    pub fn visit_children<F>(&self, f: F, payload: Payload)
+    pub fn is_unexposed(&self) -> bool {
+        self.kind().is_unexposed()
+    }
+    /// `None` for the translation unit
+    pub fn semantic_parent(&self) -> Option<Cursor<'tu>> {
+        Self::from_raw_valid(unsafe { clang_sys::clang_getCursorSemanticParent(self.raw) })
+    }
+    /// `None` for the translation unit
+    pub fn lexical_parent(&self) -> Option<Cursor<'tu>> {
+        Self::from_raw_valid(unsafe { clang_sys::clang_getCursorLexicalParent(self.raw) })
+    }
+    /// Unified Symbol Resolution, identifies the same entity across translation units,
+    /// `None` if the cursor is not an entity
+    pub fn usr(&self) -> Option<String> {
+        let usr = unsafe { cxstring_into_string(clang_sys::clang_getCursorUSR(self.raw)) };
+        (!usr.is_empty()).then_some(usr)
+    }
+    /// the entity a reference or an expression refers to, a declaration refers to itself
+    pub fn referenced(&self) -> Option<Cursor<'tu>> {
+        Self::from_raw_valid(unsafe { clang_sys::clang_getCursorReferenced(self.raw) })
+    }
+    /// the definition of the entity, `None` if it is not defined in this translation unit
+    pub fn definition(&self) -> Option<Cursor<'tu>> {
+        Self::from_raw_valid(unsafe { clang_sys::clang_getCursorDefinition(self.raw) })
+    }
+    /// the first declaration of the entity, which is the same cursor for all its declarations
+    pub fn canonical(&self) -> Cursor<'tu> {
+        Self::from_raw(unsafe { clang_sys::clang_getCanonicalCursor(self.raw) })
+    }
+    pub fn is_definition(&self) -> bool {
+        unsafe { clang_sys::clang_isCursorDefinition(self.raw) != 0 }
+    }
+    pub fn ty(&self) -> Option<Type<'tu>> {
+        Type::from_raw(unsafe { clang_sys::clang_getCursorType(self.raw) })
+    }
+    /// the return type of a function or method
+    pub fn result_type(&self) -> Option<Type<'tu>> {
+        Type::from_raw(unsafe { clang_sys::clang_getCursorResultType(self.raw) })
+    }
+    /// the aliased type of a typedef or type alias declaration
+    pub fn underlying_typedef_type(&self) -> Option<Type<'tu>> {
+        Type::from_raw(unsafe { clang_sys::clang_getTypedefDeclUnderlyingType(self.raw) })
+    }
+    /// the integer type of an enum declaration
+    pub fn enum_integer_type(&self) -> Option<Type<'tu>> {
+        Type::from_raw(unsafe { clang_sys::clang_getEnumDeclIntegerType(self.raw) })
+    }
+    pub fn extent(&self) -> SourceRange<'_> {
+        SourceRange::from_raw(
+            unsafe { clang_sys::clang_getCursorExtent(self.raw) },
+            self.tu(),
+        )
+    }
+    /// call `f` with each child and its parent, `f` decides how the traversal goes on
+    ///
+    /// a panic inside `f` stops the traversal, and is resumed after `clang_visitChildren` returns
+    pub fn visit_children<F>(&self, f: F)
     where
-        F: Fn(&Cursor, &Cursor, Payload) -> i32,
+        F: FnMut(Cursor<'tu>, Cursor<'tu>) -> ChildVisit,
     {
-        trait NodeCallback {
-            fn call(&self, cursor: &Cursor, parent: &Cursor, payload: Payload) -> i32;
-        }
-        impl<F> NodeCallback for F
-        where
-            F: Fn(&Cursor, &Cursor, Payload) -> i32,
-        {
-            fn call(&self, cursor: &Cursor, parent: &Cursor, payload: Payload) -> i32 {
-                self(cursor, parent, payload)
-            }
+        struct State<F> {
+            f: F,
+            panic: CaughtPanic,
         }
-        extern "C" fn visitor(
+        extern "C" fn visitor<'tu, F>(
             cursor: clang_sys::CXCursor,
             parent: clang_sys::CXCursor,
             data: clang_sys::CXClientData,
-        ) -> clang_sys::CXChildVisitResult {
-            let (f, payload) = unsafe { *(data as *mut (&dyn NodeCallback, Payload)) };
-            f.call(
-                &Cursor::from_raw(cursor),
-                &Cursor::from_raw(parent),
-                payload,
-            )
+        ) -> clang_sys::CXChildVisitResult
+        where
+            F: FnMut(Cursor<'tu>, Cursor<'tu>) -> ChildVisit,
+        {
+            let state = unsafe { &mut *(data as *mut State<F>) };
+            let f = &mut state.f;
+            state
+                .panic
+                .catch(|| f(Cursor::from_raw(cursor), Cursor::from_raw(parent)))
+                .map_or(clang_sys::CXChildVisit_Break, ChildVisit::to_raw)
         }
-        let callback = &f as &dyn NodeCallback;
-        let mut payload = (callback, payload);
+        let mut state = State {
+            f,
+            panic: CaughtPanic::default(),
+        };
         unsafe {
             clang_sys::clang_visitChildren(
                 self.raw,
-                visitor,
-                &mut payload as *mut _ as clang_sys::CXClientData,
+                visitor::<F>,
+                &mut state as *mut State<F> as clang_sys::CXClientData,
             )
         };
+        state.panic.resume();
     }
 }
 
 impl<'index> TranslationUnit<'index> {
     pub fn create_cursor(&self) -> Cursor<'_> {
-        assert!(!self.raw.is_null());
         let raw = unsafe { clang_sys::clang_getTranslationUnitCursor(self.raw) };
         assert_eq!(unsafe { clang_sys::clang_Cursor_isNull(raw) }, 0);
         Cursor {
@@ -251,89 +637,218 @@ impl<'index> TranslationUnit<'index> {
             _tu: PhantomData,
         }
     }
+    /// parse the translation unit again with `unsaved_files`, using its original arguments
+    ///
+    /// it takes `self` so that cursors into the old AST cannot outlive the reparse
+    ///
+    /// ```compile_fail
+    /// # use std::path::Path;
+    /// # use clang_rs_binding::{clang::Clang, index::ParseOptions};
+    /// let clang = Clang::new();
+    /// let index = clang.create_index();
+    /// let path = Path::new("a.cpp");
+    /// let tu = index
+    ///     .parse(path, &["-std=c++17"], &[(path, "int a;")], ParseOptions::NONE)
+    ///     .unwrap();
+    /// let cursor = tu.create_cursor();
+    /// let tu = tu.reparse(&[(path, "int b;")]).unwrap();
+    /// cursor.spelling();
+    /// ```
+    ///
+    /// on failure the translation unit is disposed, as libclang requires after a failed reparse
+    pub fn reparse(self, unsaved_files: &[UnsavedFile]) -> Result<Self> {
+        let mut unsaved_files = RawUnsavedFiles::new(unsaved_files)?;
+        let code = unsafe {
+            clang_sys::clang_reparseTranslationUnit(
+                self.raw,
+                unsaved_files.len(),
+                unsaved_files.as_mut_ptr(),
+                clang_sys::clang_defaultReparseOptions(self.raw),
+            )
+        };
+        // on failure, dropping `self` disposes the translation unit
+        ParseError::from_error_code(code).map_err(Error::Reparse)?;
+        Ok(self)
+    }
+    /// save the translation unit as an AST file, which can be loaded by [`Index::create_translation_unit`]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let filename = path_to_cstring(&path)?;
+        let error = unsafe {
+            clang_sys::clang_saveTranslationUnit(
+                self.raw,
+                filename.as_ptr(),
+                clang_sys::clang_defaultSaveOptions(self.raw),
+            )
+        };
+        SaveError::from_raw(error).map_err(|error| Error::Save {
+            path: path.as_ref().to_owned(),
+            error,
+        })
+    }
+    /// the location of `line` and `column` (both 1-based) in `file`
+    pub fn location(&self, file: &File, line: u32, column: u32) -> SourceLocation<'_> {
+        SourceLocation::from_raw(
+            unsafe { clang_sys::clang_getLocation(self.raw, file.raw(), line, column) },
+            self.raw,
+        )
+    }
+    /// the location of the byte `offset` in `file`
+    pub fn location_for_offset(&self, file: &File, offset: u32) -> SourceLocation<'_> {
+        SourceLocation::from_raw(
+            unsafe { clang_sys::clang_getLocationForOffset(self.raw, file.raw(), offset) },
+            self.raw,
+        )
+    }
 }
 
 #[derive(Debug)]
 pub struct SourceRange<'cursor> {
     raw: clang_sys::CXSourceRange,
+    tu: clang_sys::CXTranslationUnit,
     _cursor: PhantomData<&'cursor Cursor<'cursor>>,
 }
 
 impl<'cursor> SourceRange<'cursor> {
+    pub(crate) fn from_raw(
+        raw: clang_sys::CXSourceRange,
+        tu: clang_sys::CXTranslationUnit,
+    ) -> Self {
+        Self {
+            raw,
+            tu,
+            _cursor: PhantomData,
+        }
+    }
+
     pub fn start(&self) -> SourceLocation<'_> {
-        unsafe { SourceLocation::from_raw(clang_sys::clang_getRangeStart(self.raw)) }
+        SourceLocation::from_raw(unsafe { clang_sys::clang_getRangeStart(self.raw) }, self.tu)
     }
     pub fn end(&self) -> SourceLocation<'_> {
-        unsafe { SourceLocation::from_raw(clang_sys::clang_getRangeEnd(self.raw)) }
+        SourceLocation::from_raw(unsafe { clang_sys::clang_getRangeEnd(self.raw) }, self.tu)
     }
 }
 
-#[derive(Debug)]
-pub struct File {
-    raw: clang_sys::CXFile,
-}
-
-impl File {
-    pub(crate) fn from_raw(raw: clang_sys::CXFile) -> Self {
-        Self { raw }
-    }
-}
-
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct SourceLocation<'source_range> {
     raw: clang_sys::CXSourceLocation,
+    tu: clang_sys::CXTranslationUnit,
     _source_range: PhantomData<&'source_range SourceRange<'source_range>>,
 }
 
-pub struct SpellingLocation {
-    pub file: File,
+/// a location in a file, returned by [`SourceLocation::spelling_location`],
+/// [`SourceLocation::expansion_location`] and [`SourceLocation::file_location`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpellingLocation<'tu> {
+    /// `None` for locations outside of any file, e.g. the null location
+    pub file: Option<File<'tu>>,
     pub line: u32,
     pub column: u32,
     pub offset: u32,
 }
 
-impl std::fmt::Debug for SpellingLocation {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let filename = unsafe { cxstring_into_string(clang_sys::clang_getFileName(self.file.raw)) };
-        f.debug_struct("SpellingLocation")
-            .field("file", &filename)
-            .field("line", &self.line)
-            .field("column", &self.column)
-            .field("offset", &self.offset)
-            .finish()
-    }
+/// a location as reported to the user, honoring `#line` directives
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PresumedLocation {
+    pub filename: String,
+    pub line: u32,
+    pub column: u32,
 }
 
+type RawFileLocationFn = unsafe fn(
+    clang_sys::CXSourceLocation,
+    *mut clang_sys::CXFile,
+    *mut std::os::raw::c_uint,
+    *mut std::os::raw::c_uint,
+    *mut std::os::raw::c_uint,
+);
+
 impl<'source_range> SourceLocation<'source_range> {
-    pub(crate) fn from_raw(raw: clang_sys::CXSourceLocation) -> Self {
+    pub(crate) fn from_raw(
+        raw: clang_sys::CXSourceLocation,
+        tu: clang_sys::CXTranslationUnit,
+    ) -> Self {
         Self {
             raw,
+            tu,
             _source_range: PhantomData,
         }
     }
-    pub fn spelling_location(&self) -> SpellingLocation {
+    fn file_location_with(&self, f: RawFileLocationFn) -> SpellingLocation<'source_range> {
         let mut file = std::ptr::null_mut();
         let mut line = 0;
         let mut column = 0;
         let mut offset = 0;
-        unsafe {
-            clang_sys::clang_getSpellingLocation(
-                self.raw,
-                &mut file,
-                &mut line,
-                &mut column,
-                &mut offset,
-            );
-        }
+        unsafe { f(self.raw, &mut file, &mut line, &mut column, &mut offset) };
         SpellingLocation {
-            file: File::from_raw(file),
+            file: File::from_raw(file, self.tu),
             line,
             column,
             offset,
         }
     }
+    /// where the token was spelled, inside the macro definition for macro expansions
+    pub fn spelling_location(&self) -> SpellingLocation<'source_range> {
+        self.file_location_with(clang_sys::clang_getSpellingLocation)
+    }
+    /// where the macro was expanded if the location is inside a macro expansion
+    pub fn expansion_location(&self) -> SpellingLocation<'source_range> {
+        self.file_location_with(clang_sys::clang_getExpansionLocation)
+    }
+    /// legacy name of [`SourceLocation::expansion_location`]
+    pub fn instantiation_location(&self) -> SpellingLocation<'source_range> {
+        self.file_location_with(clang_sys::clang_getInstantiationLocation)
+    }
+    /// like [`SourceLocation::expansion_location`], but for macro arguments it is the
+    /// location of the argument at the call site
+    pub fn file_location(&self) -> SpellingLocation<'source_range> {
+        self.file_location_with(clang_sys::clang_getFileLocation)
+    }
+    pub fn presumed_location(&self) -> PresumedLocation {
+        let mut filename = clang_sys::CXString::default();
+        let mut line = 0;
+        let mut column = 0;
+        unsafe {
+            clang_sys::clang_getPresumedLocation(self.raw, &mut filename, &mut line, &mut column);
+            PresumedLocation {
+                filename: cxstring_into_string(filename),
+                line,
+                column,
+            }
+        }
+    }
+    pub fn is_in_system_header(&self) -> bool {
+        unsafe { clang_sys::clang_Location_isInSystemHeader(self.raw) != 0 }
+    }
+    /// whether the location is in the main file of the translation unit, `false` for included files
+    pub fn is_from_main_file(&self) -> bool {
+        unsafe { clang_sys::clang_Location_isFromMainFile(self.raw) != 0 }
+    }
+    /// compares the offsets of the file locations, `None` if they are in different files or
+    /// either is outside of any file
+    ///
+    /// clang-sys 1.9.1 does not expose `clang_isBeforeInTranslationUnit`, so locations in
+    /// different files can not be ordered, e.g. a location in a header and one in the main file
+    /// which includes it
+    ///
+    /// this is not a `PartialOrd`, because distinct locations may compare as equal,
+    /// e.g. a macro argument and the location it was spelled at
+    pub fn cmp_file_offset(&self, other: &Self) -> Option<Ordering> {
+        let (lhs, rhs) = (self.file_location(), other.file_location());
+        match (lhs.file, rhs.file) {
+            (Some(l), Some(r)) if l == r => Some(lhs.offset.cmp(&rhs.offset)),
+            _ => None,
+        }
+    }
 }
 
+impl<'source_range> PartialEq for SourceLocation<'source_range> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { clang_sys::clang_equalLocations(self.raw, other.raw) != 0 }
+    }
+}
+
+impl<'source_range> Eq for SourceLocation<'source_range> {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -368,34 +883,70 @@ mod test {
         };
         is_ffi_struct(&cursor);
 
-        let child_visit_result = ChildVisitResult {};
-        is_small_value_struct(&child_visit_result);
+        is_small_value_struct(&ParseOptions::INCOMPLETE);
+        is_small_value_enum(&ParseError::Crashed);
+        is_small_value_enum(&SaveError::TranslationErrors);
 
-        let source_range = SourceRange {
-            raw: clang_sys::CXSourceRange::default(),
-            _cursor: PhantomData,
-        };
+        let unsaved_files = RawUnsavedFiles::new(&[(Path::new("a.cpp"), "int a;")]).unwrap();
+        is_ffi_struct(&unsaved_files);
+
+        let child_visit = ChildVisit::Recurse;
+        is_small_value_enum(&child_visit);
+
+        let source_range =
+            SourceRange::from_raw(clang_sys::CXSourceRange::default(), std::ptr::null_mut());
         is_ffi_struct(&source_range);
 
-        let source_location = SourceLocation {
-            raw: clang_sys::CXSourceLocation::default(),
-            _source_range: PhantomData,
-        };
+        let source_location =
+            SourceLocation::from_raw(clang_sys::CXSourceLocation::default(), std::ptr::null_mut());
         is_ffi_struct(&source_location);
 
-        let file = File {
-            raw: std::ptr::null_mut() as clang_sys::CXFile,
-        };
-        is_ffi_struct(&file);
-
         let spelling_location = SpellingLocation {
-            file: File {
-                raw: std::ptr::null_mut() as clang_sys::CXFile,
-            },
+            file: None,
             line: 0,
             column: 0,
             offset: 0,
         };
         is_ffi_struct(&spelling_location);
     }
+
+    #[test]
+    fn unsaved_files() {
+        let contents = "int main() {}";
+        let mut unsaved_files = RawUnsavedFiles::new(&[(Path::new("main.cpp"), contents)]).unwrap();
+        assert_eq!(unsaved_files.len(), 1);
+        let raw = unsafe { &*unsaved_files.as_mut_ptr() };
+        let filename = unsafe { std::ffi::CStr::from_ptr(raw.Filename) };
+        assert_eq!(filename.to_str(), Ok("main.cpp"));
+        assert_eq!(raw.Contents as *const u8, contents.as_ptr());
+        assert_eq!(raw.Length as usize, contents.len());
+    }
+
+    #[test]
+    fn error_code() {
+        assert_eq!(
+            ParseError::from_error_code(clang_sys::CXError_Success),
+            Ok(())
+        );
+        assert_eq!(
+            ParseError::from_error_code(clang_sys::CXError_ASTReadError),
+            Err(ParseError::AstReadError)
+        );
+        assert_eq!(
+            SaveError::from_raw(clang_sys::CXSaveError_InvalidTU),
+            Err(SaveError::InvalidTranslationUnit)
+        );
+    }
+
+    #[test]
+    fn parse_options() {
+        let options = ParseOptions::DETAILED_PREPROCESSING_RECORD | ParseOptions::KEEP_GOING;
+        assert_eq!(
+            options.bits(),
+            clang_sys::CXTranslationUnit_DetailedPreprocessingRecord
+                | clang_sys::CXTranslationUnit_KeepGoing
+        );
+        assert!(!options.contains(ParseOptions::INCOMPLETE));
+        assert_eq!(ParseOptions::default(), ParseOptions::NONE);
+    }
 }
@@ -0,0 +1,315 @@
+use std::marker::PhantomData;
+
+use super::{Cursor, SourceRange, TranslationUnit};
+use crate::utility::cxstring_into_string;
+
+/// how an inline command like `\b` renders its argument, mirrors `CXCommentInlineCommandRenderKind`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InlineCommandRenderKind {
+    Normal,
+    Bold,
+    Monospaced,
+    Emphasized,
+    /// e.g. `\anchor`, which renders no text
+    Anchor,
+}
+
+/// `CXCommentInlineCommandRenderKind_Anchor`, added in libclang 17 and not defined by clang-sys
+const CX_COMMENT_INLINE_COMMAND_RENDER_KIND_ANCHOR: clang_sys::CXCommentInlineCommandRenderKind = 4;
+
+impl InlineCommandRenderKind {
+    fn from_raw(raw: clang_sys::CXCommentInlineCommandRenderKind) -> Self {
+        match raw {
+            clang_sys::CXCommentInlineCommandRenderKind_Normal => Self::Normal,
+            clang_sys::CXCommentInlineCommandRenderKind_Bold => Self::Bold,
+            clang_sys::CXCommentInlineCommandRenderKind_Monospaced => Self::Monospaced,
+            clang_sys::CXCommentInlineCommandRenderKind_Emphasized => Self::Emphasized,
+            CX_COMMENT_INLINE_COMMAND_RENDER_KIND_ANCHOR => Self::Anchor,
+            k => unreachable!("unexpected CXCommentInlineCommandRenderKind {}", k),
+        }
+    }
+}
+
+/// how a function parameter is passed, mirrors `CXCommentParamPassDirection`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ParamDirection {
+    In,
+    Out,
+    InOut,
+}
+
+impl ParamDirection {
+    fn from_raw(raw: clang_sys::CXCommentParamPassDirection) -> Self {
+        match raw {
+            clang_sys::CXCommentParamPassDirection_In => Self::In,
+            clang_sys::CXCommentParamPassDirection_Out => Self::Out,
+            clang_sys::CXCommentParamPassDirection_InOut => Self::InOut,
+            d => unreachable!("unexpected CXCommentParamPassDirection {}", d),
+        }
+    }
+}
+
+/// content of a paragraph
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InlineComment {
+    /// plain text, including leading whitespace
+    Text(String),
+    /// e.g. `\b word`
+    Command {
+        name: String,
+        args: Vec<String>,
+        render_kind: InlineCommandRenderKind,
+    },
+    /// e.g. `<a href="x">`, attributes are `(name, value)` pairs
+    HtmlStartTag {
+        name: String,
+        attributes: Vec<(String, String)>,
+        is_self_closing: bool,
+    },
+    HtmlEndTag {
+        name: String,
+    },
+}
+
+/// a top-level part of a [`Comment`]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BlockComment {
+    Paragraph(Vec<InlineComment>),
+    /// e.g. `\returns the sum` or `\brief`
+    Command {
+        name: String,
+        args: Vec<String>,
+        paragraph: Vec<InlineComment>,
+    },
+    /// `\param`, `index` is `None` if no parameter is named `name`
+    Param {
+        name: String,
+        index: Option<u32>,
+        direction: ParamDirection,
+        /// the direction is written as in `\param[out]`
+        is_direction_explicit: bool,
+        paragraph: Vec<InlineComment>,
+    },
+    /// `\tparam`, `position` has the index at each nesting level of template parameter lists,
+    /// `None` if no template parameter is named `name`
+    TParam {
+        name: String,
+        position: Option<Vec<u32>>,
+        paragraph: Vec<InlineComment>,
+    },
+    /// e.g. `\verbatim` up to `\endverbatim`
+    VerbatimBlock {
+        name: String,
+        lines: Vec<String>,
+    },
+    /// a command taking the rest of the line as is, e.g. `\fn`
+    VerbatimLine {
+        name: String,
+        text: String,
+    },
+}
+
+fn children(raw: clang_sys::CXComment) -> impl Iterator<Item = clang_sys::CXComment> {
+    let n = unsafe { clang_sys::clang_Comment_getNumChildren(raw) };
+    (0..n).map(move |i| unsafe { clang_sys::clang_Comment_getChild(raw, i) })
+}
+
+fn kind(raw: clang_sys::CXComment) -> clang_sys::CXCommentKind {
+    unsafe { clang_sys::clang_Comment_getKind(raw) }
+}
+
+fn inline_from_raw(raw: clang_sys::CXComment) -> InlineComment {
+    unsafe {
+        match kind(raw) {
+            clang_sys::CXComment_Text => InlineComment::Text(cxstring_into_string(
+                clang_sys::clang_TextComment_getText(raw),
+            )),
+            clang_sys::CXComment_InlineCommand => InlineComment::Command {
+                name: cxstring_into_string(clang_sys::clang_InlineCommandComment_getCommandName(
+                    raw,
+                )),
+                args: (0..clang_sys::clang_InlineCommandComment_getNumArgs(raw))
+                    .map(|i| {
+                        cxstring_into_string(clang_sys::clang_InlineCommandComment_getArgText(
+                            raw, i,
+                        ))
+                    })
+                    .collect(),
+                render_kind: InlineCommandRenderKind::from_raw(
+                    clang_sys::clang_InlineCommandComment_getRenderKind(raw),
+                ),
+            },
+            clang_sys::CXComment_HTMLStartTag => InlineComment::HtmlStartTag {
+                name: cxstring_into_string(clang_sys::clang_HTMLTagComment_getTagName(raw)),
+                attributes: (0..clang_sys::clang_HTMLStartTag_getNumAttrs(raw))
+                    .map(|i| {
+                        (
+                            cxstring_into_string(clang_sys::clang_HTMLStartTag_getAttrName(raw, i)),
+                            cxstring_into_string(clang_sys::clang_HTMLStartTag_getAttrValue(
+                                raw, i,
+                            )),
+                        )
+                    })
+                    .collect(),
+                is_self_closing: clang_sys::clang_HTMLStartTagComment_isSelfClosing(raw) != 0,
+            },
+            clang_sys::CXComment_HTMLEndTag => InlineComment::HtmlEndTag {
+                name: cxstring_into_string(clang_sys::clang_HTMLTagComment_getTagName(raw)),
+            },
+            k => unreachable!("unexpected inline CXCommentKind {}", k),
+        }
+    }
+}
+
+fn paragraph_from_raw(raw: clang_sys::CXComment) -> Vec<InlineComment> {
+    children(raw).map(inline_from_raw).collect()
+}
+
+fn block_from_raw(raw: clang_sys::CXComment) -> BlockComment {
+    unsafe {
+        let name =
+            || cxstring_into_string(clang_sys::clang_BlockCommandComment_getCommandName(raw));
+        let paragraph =
+            || paragraph_from_raw(clang_sys::clang_BlockCommandComment_getParagraph(raw));
+        match kind(raw) {
+            clang_sys::CXComment_Paragraph => BlockComment::Paragraph(paragraph_from_raw(raw)),
+            clang_sys::CXComment_BlockCommand => BlockComment::Command {
+                name: name(),
+                args: (0..clang_sys::clang_BlockCommandComment_getNumArgs(raw))
+                    .map(|i| {
+                        cxstring_into_string(clang_sys::clang_BlockCommandComment_getArgText(
+                            raw, i,
+                        ))
+                    })
+                    .collect(),
+                paragraph: paragraph(),
+            },
+            clang_sys::CXComment_ParamCommand => BlockComment::Param {
+                name: cxstring_into_string(clang_sys::clang_ParamCommandComment_getParamName(raw)),
+                index: (clang_sys::clang_ParamCommandComment_isParamIndexValid(raw) != 0)
+                    .then(|| clang_sys::clang_ParamCommandComment_getParamIndex(raw)),
+                direction: ParamDirection::from_raw(
+                    clang_sys::clang_ParamCommandComment_getDirection(raw),
+                ),
+                is_direction_explicit: clang_sys::clang_ParamCommandComment_isDirectionExplicit(
+                    raw,
+                ) != 0,
+                paragraph: paragraph(),
+            },
+            clang_sys::CXComment_TParamCommand => BlockComment::TParam {
+                name: cxstring_into_string(clang_sys::clang_TParamCommandComment_getParamName(raw)),
+                position: (clang_sys::clang_TParamCommandComment_isParamPositionValid(raw) != 0)
+                    .then(|| {
+                        (0..clang_sys::clang_TParamCommandComment_getDepth(raw))
+                            .map(|depth| clang_sys::clang_TParamCommandComment_getIndex(raw, depth))
+                            .collect()
+                    }),
+                paragraph: paragraph(),
+            },
+            clang_sys::CXComment_VerbatimBlockCommand => BlockComment::VerbatimBlock {
+                name: name(),
+                lines: children(raw)
+                    .map(|line| {
+                        cxstring_into_string(clang_sys::clang_VerbatimBlockLineComment_getText(
+                            line,
+                        ))
+                    })
+                    .collect(),
+            },
+            clang_sys::CXComment_VerbatimLine => BlockComment::VerbatimLine {
+                name: name(),
+                text: cxstring_into_string(clang_sys::clang_VerbatimLineComment_getText(raw)),
+            },
+            k => unreachable!("unexpected block CXCommentKind {}", k),
+        }
+    }
+}
+
+/// a parsed documentation comment, see [`Cursor::parsed_comment`]
+#[derive(Debug, Clone, Copy)]
+pub struct Comment<'tu> {
+    raw: clang_sys::CXComment,
+    _tu: PhantomData<&'tu TranslationUnit<'tu>>,
+}
+
+impl<'tu> Comment<'tu> {
+    fn from_raw(raw: clang_sys::CXComment) -> Option<Self> {
+        (kind(raw) == clang_sys::CXComment_FullComment).then_some(Self {
+            raw,
+            _tu: PhantomData,
+        })
+    }
+
+    /// paragraphs and commands in order, paragraphs of only whitespace are left out
+    pub fn blocks(&self) -> Vec<BlockComment> {
+        children(self.raw)
+            .filter(|&child| unsafe { clang_sys::clang_Comment_isWhitespace(child) } == 0)
+            .map(block_from_raw)
+            .collect()
+    }
+    /// HTML fragment the way clang renders it, e.g. with `<p class="para-brief">`
+    pub fn to_html(&self) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_FullComment_getAsHTML(self.raw)) }
+    }
+    /// XML following clang's `CommentXML.rng` schema, including the declaration
+    pub fn to_xml(&self) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_FullComment_getAsXML(self.raw)) }
+    }
+}
+
+impl<'tu> Cursor<'tu> {
+    /// the documentation comment attached to this declaration as written, `None` if there is none
+    pub fn raw_comment(&self) -> Option<String> {
+        let comment =
+            unsafe { cxstring_into_string(clang_sys::clang_Cursor_getRawCommentText(self.raw)) };
+        (!comment.is_empty()).then_some(comment)
+    }
+    /// the first paragraph or the `\brief` paragraph of the documentation comment
+    pub fn brief_comment(&self) -> Option<String> {
+        let comment =
+            unsafe { cxstring_into_string(clang_sys::clang_Cursor_getBriefCommentText(self.raw)) };
+        (!comment.is_empty()).then_some(comment)
+    }
+    /// `None` if there is no documentation comment
+    pub fn comment_range(&self) -> Option<SourceRange<'_>> {
+        let range = unsafe { clang_sys::clang_Cursor_getCommentRange(self.raw) };
+        (unsafe { clang_sys::clang_Range_isNull(range) } == 0)
+            .then(|| SourceRange::from_raw(range, self.tu()))
+    }
+    pub fn parsed_comment(&self) -> Option<Comment<'tu>> {
+        Comment::from_raw(unsafe { clang_sys::clang_Cursor_getParsedComment(self.raw) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_small_value_enum(&InlineCommandRenderKind::Bold);
+        is_small_value_enum(&ParamDirection::InOut);
+        is_value_enum(&InlineComment::Text(String::new()));
+        is_value_enum(&BlockComment::Paragraph(Vec::new()));
+    }
+
+    #[test]
+    fn raw_values() {
+        assert_eq!(
+            InlineCommandRenderKind::from_raw(
+                clang_sys::CXCommentInlineCommandRenderKind_Monospaced
+            ),
+            InlineCommandRenderKind::Monospaced
+        );
+        assert_eq!(
+            InlineCommandRenderKind::from_raw(CX_COMMENT_INLINE_COMMAND_RENDER_KIND_ANCHOR),
+            InlineCommandRenderKind::Anchor
+        );
+        assert_eq!(
+            ParamDirection::from_raw(clang_sys::CXCommentParamPassDirection_Out),
+            ParamDirection::Out
+        );
+    }
+}
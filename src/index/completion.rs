@@ -0,0 +1,411 @@
+use std::marker::PhantomData;
+use std::path::Path;
+
+use super::{
+    CursorKind, Diagnostics, FixIt, RawUnsavedFiles, SourceRange, TranslationUnit, UnsavedFile,
+};
+use crate::error::{Error, Result};
+use crate::utility::{cxstring_into_string, define_flags, path_to_cstring};
+
+define_flags! {
+    /// what [`TranslationUnit::complete_at`] includes, mirrors `CXCodeComplete_Flags`
+    pub struct CodeCompleteOptions: clang_sys::CXCodeComplete_Flags {
+        /// include macros
+        const INCLUDE_MACROS = clang_sys::CXCodeComplete_IncludeMacros;
+        /// include code patterns for language constructs, e.g. `for` loops
+        const INCLUDE_CODE_PATTERNS = clang_sys::CXCodeComplete_IncludeCodePatterns;
+        /// include brief documentation comments
+        const INCLUDE_BRIEF_COMMENTS = clang_sys::CXCodeComplete_IncludeBriefComments;
+        /// only complete declarations from the main file, skipping the preamble
+        const SKIP_PREAMBLE = clang_sys::CXCodeComplete_SkipPreamble;
+        /// include completions which need fix-its, e.g. `.` replaced with `->`
+        const INCLUDE_COMPLETIONS_WITH_FIX_ITS = clang_sys::CXCodeComplete_IncludeCompletionsWithFixIts;
+    }
+}
+
+impl CodeCompleteOptions {
+    /// the options clang suggests
+    pub fn clang_default() -> Self {
+        Self::from_raw(unsafe { clang_sys::clang_defaultCodeCompleteOptions() })
+    }
+}
+
+define_flags! {
+    /// the kinds of completions appropriate at the completion point, mirrors `CXCompletionContext`
+    pub struct CompletionContext: u64 {
+        const ANY_TYPE = clang_sys::CXCompletionContext_AnyType;
+        const ANY_VALUE = clang_sys::CXCompletionContext_AnyValue;
+        const OBJC_OBJECT_VALUE = clang_sys::CXCompletionContext_ObjCObjectValue;
+        const OBJC_SELECTOR_VALUE = clang_sys::CXCompletionContext_ObjCSelectorValue;
+        const CXX_CLASS_TYPE_VALUE = clang_sys::CXCompletionContext_CXXClassTypeValue;
+        const DOT_MEMBER_ACCESS = clang_sys::CXCompletionContext_DotMemberAccess;
+        const ARROW_MEMBER_ACCESS = clang_sys::CXCompletionContext_ArrowMemberAccess;
+        const OBJC_PROPERTY_ACCESS = clang_sys::CXCompletionContext_ObjCPropertyAccess;
+        const ENUM_TAG = clang_sys::CXCompletionContext_EnumTag;
+        const UNION_TAG = clang_sys::CXCompletionContext_UnionTag;
+        const STRUCT_TAG = clang_sys::CXCompletionContext_StructTag;
+        const CLASS_TAG = clang_sys::CXCompletionContext_ClassTag;
+        const NAMESPACE = clang_sys::CXCompletionContext_Namespace;
+        const NESTED_NAME_SPECIFIER = clang_sys::CXCompletionContext_NestedNameSpecifier;
+        const OBJC_INTERFACE = clang_sys::CXCompletionContext_ObjCInterface;
+        const OBJC_PROTOCOL = clang_sys::CXCompletionContext_ObjCProtocol;
+        const OBJC_CATEGORY = clang_sys::CXCompletionContext_ObjCCategory;
+        const OBJC_INSTANCE_MESSAGE = clang_sys::CXCompletionContext_ObjCInstanceMessage;
+        const OBJC_CLASS_MESSAGE = clang_sys::CXCompletionContext_ObjCClassMessage;
+        const OBJC_SELECTOR_NAME = clang_sys::CXCompletionContext_ObjCSelectorName;
+        const MACRO_NAME = clang_sys::CXCompletionContext_MacroName;
+        const NATURAL_LANGUAGE = clang_sys::CXCompletionContext_NaturalLanguage;
+        const INCLUDED_FILE = clang_sys::CXCompletionContext_IncludedFile;
+        /// the context is unknown, all kinds of completions may be appropriate
+        const UNKNOWN = clang_sys::CXCompletionContext_Unknown;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Availability {
+    Available,
+    Deprecated,
+    NotAvailable,
+    /// available, but not accessible from the completion point, e.g. a private member
+    NotAccessible,
+}
+
+impl Availability {
+    fn from_raw(raw: clang_sys::CXAvailabilityKind) -> Self {
+        match raw {
+            clang_sys::CXAvailability_Available => Self::Available,
+            clang_sys::CXAvailability_Deprecated => Self::Deprecated,
+            clang_sys::CXAvailability_NotAvailable => Self::NotAvailable,
+            clang_sys::CXAvailability_NotAccessible => Self::NotAccessible,
+            a => unreachable!("unexpected CXAvailabilityKind {}", a),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CompletionChunkKind {
+    /// a nested completion string which may be left out, see [`CompletionChunk::optional`]
+    Optional,
+    /// the text the user types to select this completion
+    TypedText,
+    Text,
+    /// a placeholder for an argument the user has to fill in
+    Placeholder,
+    /// informative text which is not inserted
+    Informative,
+    /// the parameter the user is currently typing in a call
+    CurrentParameter,
+    LeftParen,
+    RightParen,
+    LeftBracket,
+    RightBracket,
+    LeftBrace,
+    RightBrace,
+    LeftAngle,
+    RightAngle,
+    Comma,
+    /// the type of the result, not inserted
+    ResultType,
+    Colon,
+    SemiColon,
+    Equal,
+    HorizontalSpace,
+    VerticalSpace,
+}
+
+impl CompletionChunkKind {
+    fn from_raw(raw: clang_sys::CXCompletionChunkKind) -> Self {
+        match raw {
+            clang_sys::CXCompletionChunk_Optional => Self::Optional,
+            clang_sys::CXCompletionChunk_TypedText => Self::TypedText,
+            clang_sys::CXCompletionChunk_Text => Self::Text,
+            clang_sys::CXCompletionChunk_Placeholder => Self::Placeholder,
+            clang_sys::CXCompletionChunk_Informative => Self::Informative,
+            clang_sys::CXCompletionChunk_CurrentParameter => Self::CurrentParameter,
+            clang_sys::CXCompletionChunk_LeftParen => Self::LeftParen,
+            clang_sys::CXCompletionChunk_RightParen => Self::RightParen,
+            clang_sys::CXCompletionChunk_LeftBracket => Self::LeftBracket,
+            clang_sys::CXCompletionChunk_RightBracket => Self::RightBracket,
+            clang_sys::CXCompletionChunk_LeftBrace => Self::LeftBrace,
+            clang_sys::CXCompletionChunk_RightBrace => Self::RightBrace,
+            clang_sys::CXCompletionChunk_LeftAngle => Self::LeftAngle,
+            clang_sys::CXCompletionChunk_RightAngle => Self::RightAngle,
+            clang_sys::CXCompletionChunk_Comma => Self::Comma,
+            clang_sys::CXCompletionChunk_ResultType => Self::ResultType,
+            clang_sys::CXCompletionChunk_Colon => Self::Colon,
+            clang_sys::CXCompletionChunk_SemiColon => Self::SemiColon,
+            clang_sys::CXCompletionChunk_Equal => Self::Equal,
+            clang_sys::CXCompletionChunk_HorizontalSpace => Self::HorizontalSpace,
+            clang_sys::CXCompletionChunk_VerticalSpace => Self::VerticalSpace,
+            k => unreachable!("unexpected CXCompletionChunkKind {}", k),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CompletionChunk<'results> {
+    pub kind: CompletionChunkKind,
+    /// empty for [`CompletionChunkKind::Optional`]
+    pub text: String,
+    /// the nested completion string of a [`CompletionChunkKind::Optional`] chunk
+    pub optional: Option<CompletionString<'results>>,
+}
+
+/// how to present and insert a completion
+#[derive(Debug, Clone, Copy)]
+pub struct CompletionString<'results> {
+    raw: clang_sys::CXCompletionString,
+    _results: PhantomData<&'results CompletionResults<'results>>,
+}
+
+impl<'results> CompletionString<'results> {
+    fn from_raw(raw: clang_sys::CXCompletionString) -> Option<Self> {
+        (!raw.is_null()).then_some(Self {
+            raw,
+            _results: PhantomData,
+        })
+    }
+
+    /// smaller is more likely to be what the user wants
+    pub fn priority(&self) -> u32 {
+        unsafe { clang_sys::clang_getCompletionPriority(self.raw) }
+    }
+    pub fn availability(&self) -> Availability {
+        Availability::from_raw(unsafe { clang_sys::clang_getCompletionAvailability(self.raw) })
+    }
+    /// only present with [`CodeCompleteOptions::INCLUDE_BRIEF_COMMENTS`]
+    pub fn brief_comment(&self) -> Option<String> {
+        let comment =
+            unsafe { cxstring_into_string(clang_sys::clang_getCompletionBriefComment(self.raw)) };
+        (!comment.is_empty()).then_some(comment)
+    }
+    pub fn annotations(&self) -> Vec<String> {
+        let n = unsafe { clang_sys::clang_getCompletionNumAnnotations(self.raw) };
+        (0..n)
+            .map(|i| unsafe {
+                cxstring_into_string(clang_sys::clang_getCompletionAnnotation(self.raw, i))
+            })
+            .collect()
+    }
+    pub fn chunks(&self) -> Vec<CompletionChunk<'results>> {
+        let n = unsafe { clang_sys::clang_getNumCompletionChunks(self.raw) };
+        (0..n)
+            .map(|i| {
+                let kind = CompletionChunkKind::from_raw(unsafe {
+                    clang_sys::clang_getCompletionChunkKind(self.raw, i)
+                });
+                let text = unsafe {
+                    cxstring_into_string(clang_sys::clang_getCompletionChunkText(self.raw, i))
+                };
+                let optional = match kind {
+                    CompletionChunkKind::Optional => CompletionString::from_raw(unsafe {
+                        clang_sys::clang_getCompletionChunkCompletionString(self.raw, i)
+                    }),
+                    _ => None,
+                };
+                CompletionChunk {
+                    kind,
+                    text,
+                    optional,
+                }
+            })
+            .collect()
+    }
+    /// text of the [`CompletionChunkKind::TypedText`] chunk
+    pub fn typed_text(&self) -> Option<String> {
+        self.chunks()
+            .into_iter()
+            .find(|chunk| chunk.kind == CompletionChunkKind::TypedText)
+            .map(|chunk| chunk.text)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CompletionResult<'results> {
+    raw: clang_sys::CXCompletionResult,
+    index: u32,
+    results: &'results CompletionResults<'results>,
+}
+
+impl<'results> CompletionResult<'results> {
+    /// the kind of entity the completion refers to
+    pub fn cursor_kind(&self) -> CursorKind {
+        CursorKind::from_raw(self.raw.CursorKind)
+    }
+    pub fn completion_string(&self) -> CompletionString<'results> {
+        CompletionString::from_raw(self.raw.CompletionString)
+            .unwrap_or_else(|| unreachable!("completion result without completion string"))
+    }
+    pub fn priority(&self) -> u32 {
+        self.completion_string().priority()
+    }
+    pub fn availability(&self) -> Availability {
+        self.completion_string().availability()
+    }
+    pub fn brief_comment(&self) -> Option<String> {
+        self.completion_string().brief_comment()
+    }
+    pub fn chunks(&self) -> Vec<CompletionChunk<'results>> {
+        self.completion_string().chunks()
+    }
+    pub fn typed_text(&self) -> Option<String> {
+        self.completion_string().typed_text()
+    }
+    /// edits to apply before inserting the completion,
+    /// only present with [`CodeCompleteOptions::INCLUDE_COMPLETIONS_WITH_FIX_ITS`]
+    pub fn fix_its(&self) -> Vec<FixIt<'results>> {
+        let results = self.results;
+        let n = unsafe { clang_sys::clang_getCompletionNumFixIts(results.raw, self.index) };
+        (0..n)
+            .map(|i| {
+                let mut range = clang_sys::CXSourceRange::default();
+                let replacement = unsafe {
+                    cxstring_into_string(clang_sys::clang_getCompletionFixIt(
+                        results.raw,
+                        self.index,
+                        i,
+                        &mut range,
+                    ))
+                };
+                FixIt {
+                    replacement,
+                    range: SourceRange::from_raw(range, results.tu),
+                }
+            })
+            .collect()
+    }
+}
+
+/// completions at a location, sorted by their typed text, see [`TranslationUnit::complete_at`]
+#[derive(Debug)]
+pub struct CompletionResults<'tu> {
+    raw: *mut clang_sys::CXCodeCompleteResults,
+    tu: clang_sys::CXTranslationUnit,
+    _tu: PhantomData<&'tu TranslationUnit<'tu>>,
+}
+
+impl<'tu> Drop for CompletionResults<'tu> {
+    fn drop(&mut self) {
+        unsafe { clang_sys::clang_disposeCodeCompleteResults(self.raw) };
+    }
+}
+
+impl<'tu> CompletionResults<'tu> {
+    fn as_slice(&self) -> &[clang_sys::CXCompletionResult] {
+        let results = unsafe { &*self.raw };
+        if results.Results.is_null() {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(results.Results, results.NumResults as usize) }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+    pub fn get(&self, index: usize) -> Option<CompletionResult<'_>> {
+        self.as_slice().get(index).map(|&raw| CompletionResult {
+            raw,
+            index: index as u32,
+            results: self,
+        })
+    }
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = CompletionResult<'_>> + '_ {
+        self.as_slice()
+            .iter()
+            .enumerate()
+            .map(move |(index, &raw)| CompletionResult {
+                raw,
+                index: index as u32,
+                results: self,
+            })
+    }
+    pub fn contexts(&self) -> CompletionContext {
+        CompletionContext::from_raw(unsafe { clang_sys::clang_codeCompleteGetContexts(self.raw) })
+    }
+    /// diagnostics produced while parsing for completion
+    ///
+    /// they belong to the whole set of results, libclang does not attribute them to single results
+    pub fn diagnostics(&self) -> Diagnostics<'_> {
+        Diagnostics::from_code_complete_results(self.raw, self.tu)
+    }
+}
+
+impl<'index> TranslationUnit<'index> {
+    /// completions at `line` and `column` (both 1-based) of `path`
+    ///
+    /// `unsaved_files` should be the same as those the translation unit was parsed or reparsed with
+    ///
+    /// libclang completes on a separate AST, so cursors into the translation unit stay valid
+    /// while the results live
+    pub fn complete_at<P: AsRef<Path>>(
+        &self,
+        path: P,
+        line: u32,
+        column: u32,
+        unsaved_files: &[UnsavedFile],
+        options: CodeCompleteOptions,
+    ) -> Result<CompletionResults<'_>> {
+        let filename = path_to_cstring(&path)?;
+        let mut unsaved_files = RawUnsavedFiles::new(unsaved_files)?;
+        let raw = unsafe {
+            clang_sys::clang_codeCompleteAt(
+                self.raw,
+                filename.as_ptr(),
+                line,
+                column,
+                unsaved_files.as_mut_ptr(),
+                unsaved_files.len(),
+                options.bits(),
+            )
+        };
+        if raw.is_null() {
+            return Err(Error::CodeComplete {
+                path: path.as_ref().to_owned(),
+                line,
+                column,
+            });
+        }
+        let results = unsafe { &*raw };
+        unsafe { clang_sys::clang_sortCodeCompletionResults(results.Results, results.NumResults) };
+        Ok(CompletionResults {
+            raw,
+            tu: self.raw,
+            _tu: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_small_value_struct(&CodeCompleteOptions::INCLUDE_MACROS);
+        is_small_value_struct(&CompletionContext::ANY_TYPE);
+        is_small_value_enum(&Availability::Available);
+        is_small_value_enum(&CompletionChunkKind::TypedText);
+
+        assert!(CompletionString::from_raw(std::ptr::null_mut()).is_none());
+
+        let results = CompletionResults {
+            raw: std::ptr::null_mut(),
+            tu: std::ptr::null_mut(),
+            _tu: PhantomData,
+        };
+        is_ffi_struct(&results);
+        std::mem::forget(results);
+    }
+
+    #[test]
+    fn context() {
+        assert!(CompletionContext::UNKNOWN.contains(CompletionContext::DOT_MEMBER_ACCESS));
+        assert!(!CompletionContext::ANY_TYPE.contains(CompletionContext::ANY_VALUE));
+    }
+}
@@ -0,0 +1,711 @@
+use crate::utility::cxstring_into_string;
+
+/// Kind of a [`Cursor`](super::Cursor), mirrors `CXCursorKind`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(i32)]
+#[non_exhaustive]
+pub enum CursorKind {
+    UnexposedDecl = clang_sys::CXCursor_UnexposedDecl,
+    StructDecl = clang_sys::CXCursor_StructDecl,
+    UnionDecl = clang_sys::CXCursor_UnionDecl,
+    ClassDecl = clang_sys::CXCursor_ClassDecl,
+    EnumDecl = clang_sys::CXCursor_EnumDecl,
+    FieldDecl = clang_sys::CXCursor_FieldDecl,
+    EnumConstantDecl = clang_sys::CXCursor_EnumConstantDecl,
+    FunctionDecl = clang_sys::CXCursor_FunctionDecl,
+    VarDecl = clang_sys::CXCursor_VarDecl,
+    ParmDecl = clang_sys::CXCursor_ParmDecl,
+    ObjCInterfaceDecl = clang_sys::CXCursor_ObjCInterfaceDecl,
+    ObjCCategoryDecl = clang_sys::CXCursor_ObjCCategoryDecl,
+    ObjCProtocolDecl = clang_sys::CXCursor_ObjCProtocolDecl,
+    ObjCPropertyDecl = clang_sys::CXCursor_ObjCPropertyDecl,
+    ObjCIvarDecl = clang_sys::CXCursor_ObjCIvarDecl,
+    ObjCInstanceMethodDecl = clang_sys::CXCursor_ObjCInstanceMethodDecl,
+    ObjCClassMethodDecl = clang_sys::CXCursor_ObjCClassMethodDecl,
+    ObjCImplementationDecl = clang_sys::CXCursor_ObjCImplementationDecl,
+    ObjCCategoryImplDecl = clang_sys::CXCursor_ObjCCategoryImplDecl,
+    TypedefDecl = clang_sys::CXCursor_TypedefDecl,
+    CXXMethod = clang_sys::CXCursor_CXXMethod,
+    Namespace = clang_sys::CXCursor_Namespace,
+    LinkageSpec = clang_sys::CXCursor_LinkageSpec,
+    Constructor = clang_sys::CXCursor_Constructor,
+    Destructor = clang_sys::CXCursor_Destructor,
+    ConversionFunction = clang_sys::CXCursor_ConversionFunction,
+    TemplateTypeParameter = clang_sys::CXCursor_TemplateTypeParameter,
+    NonTypeTemplateParameter = clang_sys::CXCursor_NonTypeTemplateParameter,
+    TemplateTemplateParameter = clang_sys::CXCursor_TemplateTemplateParameter,
+    FunctionTemplate = clang_sys::CXCursor_FunctionTemplate,
+    ClassTemplate = clang_sys::CXCursor_ClassTemplate,
+    ClassTemplatePartialSpecialization = clang_sys::CXCursor_ClassTemplatePartialSpecialization,
+    NamespaceAlias = clang_sys::CXCursor_NamespaceAlias,
+    UsingDirective = clang_sys::CXCursor_UsingDirective,
+    UsingDeclaration = clang_sys::CXCursor_UsingDeclaration,
+    TypeAliasDecl = clang_sys::CXCursor_TypeAliasDecl,
+    ObjCSynthesizeDecl = clang_sys::CXCursor_ObjCSynthesizeDecl,
+    ObjCDynamicDecl = clang_sys::CXCursor_ObjCDynamicDecl,
+    CXXAccessSpecifier = clang_sys::CXCursor_CXXAccessSpecifier,
+    ObjCSuperClassRef = clang_sys::CXCursor_ObjCSuperClassRef,
+    ObjCProtocolRef = clang_sys::CXCursor_ObjCProtocolRef,
+    ObjCClassRef = clang_sys::CXCursor_ObjCClassRef,
+    TypeRef = clang_sys::CXCursor_TypeRef,
+    CXXBaseSpecifier = clang_sys::CXCursor_CXXBaseSpecifier,
+    TemplateRef = clang_sys::CXCursor_TemplateRef,
+    NamespaceRef = clang_sys::CXCursor_NamespaceRef,
+    MemberRef = clang_sys::CXCursor_MemberRef,
+    LabelRef = clang_sys::CXCursor_LabelRef,
+    OverloadedDeclRef = clang_sys::CXCursor_OverloadedDeclRef,
+    VariableRef = clang_sys::CXCursor_VariableRef,
+    InvalidFile = clang_sys::CXCursor_InvalidFile,
+    NoDeclFound = clang_sys::CXCursor_NoDeclFound,
+    NotImplemented = clang_sys::CXCursor_NotImplemented,
+    InvalidCode = clang_sys::CXCursor_InvalidCode,
+    UnexposedExpr = clang_sys::CXCursor_UnexposedExpr,
+    DeclRefExpr = clang_sys::CXCursor_DeclRefExpr,
+    MemberRefExpr = clang_sys::CXCursor_MemberRefExpr,
+    CallExpr = clang_sys::CXCursor_CallExpr,
+    ObjCMessageExpr = clang_sys::CXCursor_ObjCMessageExpr,
+    BlockExpr = clang_sys::CXCursor_BlockExpr,
+    IntegerLiteral = clang_sys::CXCursor_IntegerLiteral,
+    FloatingLiteral = clang_sys::CXCursor_FloatingLiteral,
+    ImaginaryLiteral = clang_sys::CXCursor_ImaginaryLiteral,
+    StringLiteral = clang_sys::CXCursor_StringLiteral,
+    CharacterLiteral = clang_sys::CXCursor_CharacterLiteral,
+    ParenExpr = clang_sys::CXCursor_ParenExpr,
+    UnaryOperator = clang_sys::CXCursor_UnaryOperator,
+    ArraySubscriptExpr = clang_sys::CXCursor_ArraySubscriptExpr,
+    BinaryOperator = clang_sys::CXCursor_BinaryOperator,
+    CompoundAssignOperator = clang_sys::CXCursor_CompoundAssignOperator,
+    ConditionalOperator = clang_sys::CXCursor_ConditionalOperator,
+    CStyleCastExpr = clang_sys::CXCursor_CStyleCastExpr,
+    CompoundLiteralExpr = clang_sys::CXCursor_CompoundLiteralExpr,
+    InitListExpr = clang_sys::CXCursor_InitListExpr,
+    AddrLabelExpr = clang_sys::CXCursor_AddrLabelExpr,
+    StmtExpr = clang_sys::CXCursor_StmtExpr,
+    GenericSelectionExpr = clang_sys::CXCursor_GenericSelectionExpr,
+    GNUNullExpr = clang_sys::CXCursor_GNUNullExpr,
+    CXXStaticCastExpr = clang_sys::CXCursor_CXXStaticCastExpr,
+    CXXDynamicCastExpr = clang_sys::CXCursor_CXXDynamicCastExpr,
+    CXXReinterpretCastExpr = clang_sys::CXCursor_CXXReinterpretCastExpr,
+    CXXConstCastExpr = clang_sys::CXCursor_CXXConstCastExpr,
+    CXXFunctionalCastExpr = clang_sys::CXCursor_CXXFunctionalCastExpr,
+    CXXTypeidExpr = clang_sys::CXCursor_CXXTypeidExpr,
+    CXXBoolLiteralExpr = clang_sys::CXCursor_CXXBoolLiteralExpr,
+    CXXNullPtrLiteralExpr = clang_sys::CXCursor_CXXNullPtrLiteralExpr,
+    CXXThisExpr = clang_sys::CXCursor_CXXThisExpr,
+    CXXThrowExpr = clang_sys::CXCursor_CXXThrowExpr,
+    CXXNewExpr = clang_sys::CXCursor_CXXNewExpr,
+    CXXDeleteExpr = clang_sys::CXCursor_CXXDeleteExpr,
+    UnaryExpr = clang_sys::CXCursor_UnaryExpr,
+    ObjCStringLiteral = clang_sys::CXCursor_ObjCStringLiteral,
+    ObjCEncodeExpr = clang_sys::CXCursor_ObjCEncodeExpr,
+    ObjCSelectorExpr = clang_sys::CXCursor_ObjCSelectorExpr,
+    ObjCProtocolExpr = clang_sys::CXCursor_ObjCProtocolExpr,
+    ObjCBridgedCastExpr = clang_sys::CXCursor_ObjCBridgedCastExpr,
+    PackExpansionExpr = clang_sys::CXCursor_PackExpansionExpr,
+    SizeOfPackExpr = clang_sys::CXCursor_SizeOfPackExpr,
+    LambdaExpr = clang_sys::CXCursor_LambdaExpr,
+    ObjCBoolLiteralExpr = clang_sys::CXCursor_ObjCBoolLiteralExpr,
+    ObjCSelfExpr = clang_sys::CXCursor_ObjCSelfExpr,
+    ArraySectionExpr = clang_sys::CXCursor_ArraySectionExpr,
+    ObjCAvailabilityCheckExpr = clang_sys::CXCursor_ObjCAvailabilityCheckExpr,
+    FixedPointLiteral = clang_sys::CXCursor_FixedPointLiteral,
+    OMPArrayShapingExpr = clang_sys::CXCursor_OMPArrayShapingExpr,
+    OMPIteratorExpr = clang_sys::CXCursor_OMPIteratorExpr,
+    CXXAddrspaceCastExpr = clang_sys::CXCursor_CXXAddrspaceCastExpr,
+    ConceptSpecializationExpr = clang_sys::CXCursor_ConceptSpecializationExpr,
+    RequiresExpr = clang_sys::CXCursor_RequiresExpr,
+    CXXParenListInitExpr = clang_sys::CXCursor_CXXParenListInitExpr,
+    PackIndexingExpr = clang_sys::CXCursor_PackIndexingExpr,
+    UnexposedStmt = clang_sys::CXCursor_UnexposedStmt,
+    LabelStmt = clang_sys::CXCursor_LabelStmt,
+    CompoundStmt = clang_sys::CXCursor_CompoundStmt,
+    CaseStmt = clang_sys::CXCursor_CaseStmt,
+    DefaultStmt = clang_sys::CXCursor_DefaultStmt,
+    IfStmt = clang_sys::CXCursor_IfStmt,
+    SwitchStmt = clang_sys::CXCursor_SwitchStmt,
+    WhileStmt = clang_sys::CXCursor_WhileStmt,
+    DoStmt = clang_sys::CXCursor_DoStmt,
+    ForStmt = clang_sys::CXCursor_ForStmt,
+    GotoStmt = clang_sys::CXCursor_GotoStmt,
+    IndirectGotoStmt = clang_sys::CXCursor_IndirectGotoStmt,
+    ContinueStmt = clang_sys::CXCursor_ContinueStmt,
+    BreakStmt = clang_sys::CXCursor_BreakStmt,
+    ReturnStmt = clang_sys::CXCursor_ReturnStmt,
+    AsmStmt = clang_sys::CXCursor_AsmStmt,
+    ObjCAtTryStmt = clang_sys::CXCursor_ObjCAtTryStmt,
+    ObjCAtCatchStmt = clang_sys::CXCursor_ObjCAtCatchStmt,
+    ObjCAtFinallyStmt = clang_sys::CXCursor_ObjCAtFinallyStmt,
+    ObjCAtThrowStmt = clang_sys::CXCursor_ObjCAtThrowStmt,
+    ObjCAtSynchronizedStmt = clang_sys::CXCursor_ObjCAtSynchronizedStmt,
+    ObjCAutoreleasePoolStmt = clang_sys::CXCursor_ObjCAutoreleasePoolStmt,
+    ObjCForCollectionStmt = clang_sys::CXCursor_ObjCForCollectionStmt,
+    CXXCatchStmt = clang_sys::CXCursor_CXXCatchStmt,
+    CXXTryStmt = clang_sys::CXCursor_CXXTryStmt,
+    CXXForRangeStmt = clang_sys::CXCursor_CXXForRangeStmt,
+    SEHTryStmt = clang_sys::CXCursor_SEHTryStmt,
+    SEHExceptStmt = clang_sys::CXCursor_SEHExceptStmt,
+    SEHFinallyStmt = clang_sys::CXCursor_SEHFinallyStmt,
+    MSAsmStmt = clang_sys::CXCursor_MSAsmStmt,
+    NullStmt = clang_sys::CXCursor_NullStmt,
+    DeclStmt = clang_sys::CXCursor_DeclStmt,
+    OMPParallelDirective = clang_sys::CXCursor_OMPParallelDirective,
+    OMPSimdDirective = clang_sys::CXCursor_OMPSimdDirective,
+    OMPForDirective = clang_sys::CXCursor_OMPForDirective,
+    OMPSectionsDirective = clang_sys::CXCursor_OMPSectionsDirective,
+    OMPSectionDirective = clang_sys::CXCursor_OMPSectionDirective,
+    OMPSingleDirective = clang_sys::CXCursor_OMPSingleDirective,
+    OMPParallelForDirective = clang_sys::CXCursor_OMPParallelForDirective,
+    OMPParallelSectionsDirective = clang_sys::CXCursor_OMPParallelSectionsDirective,
+    OMPTaskDirective = clang_sys::CXCursor_OMPTaskDirective,
+    OMPMasterDirective = clang_sys::CXCursor_OMPMasterDirective,
+    OMPCriticalDirective = clang_sys::CXCursor_OMPCriticalDirective,
+    OMPTaskyieldDirective = clang_sys::CXCursor_OMPTaskyieldDirective,
+    OMPBarrierDirective = clang_sys::CXCursor_OMPBarrierDirective,
+    OMPTaskwaitDirective = clang_sys::CXCursor_OMPTaskwaitDirective,
+    OMPFlushDirective = clang_sys::CXCursor_OMPFlushDirective,
+    SEHLeaveStmt = clang_sys::CXCursor_SEHLeaveStmt,
+    OMPOrderedDirective = clang_sys::CXCursor_OMPOrderedDirective,
+    OMPAtomicDirective = clang_sys::CXCursor_OMPAtomicDirective,
+    OMPForSimdDirective = clang_sys::CXCursor_OMPForSimdDirective,
+    OMPParallelForSimdDirective = clang_sys::CXCursor_OMPParallelForSimdDirective,
+    OMPTargetDirective = clang_sys::CXCursor_OMPTargetDirective,
+    OMPTeamsDirective = clang_sys::CXCursor_OMPTeamsDirective,
+    OMPTaskgroupDirective = clang_sys::CXCursor_OMPTaskgroupDirective,
+    OMPCancellationPointDirective = clang_sys::CXCursor_OMPCancellationPointDirective,
+    OMPCancelDirective = clang_sys::CXCursor_OMPCancelDirective,
+    OMPTargetDataDirective = clang_sys::CXCursor_OMPTargetDataDirective,
+    OMPTaskLoopDirective = clang_sys::CXCursor_OMPTaskLoopDirective,
+    OMPTaskLoopSimdDirective = clang_sys::CXCursor_OMPTaskLoopSimdDirective,
+    OMPDistributeDirective = clang_sys::CXCursor_OMPDistributeDirective,
+    OMPTargetEnterDataDirective = clang_sys::CXCursor_OMPTargetEnterDataDirective,
+    OMPTargetExitDataDirective = clang_sys::CXCursor_OMPTargetExitDataDirective,
+    OMPTargetParallelDirective = clang_sys::CXCursor_OMPTargetParallelDirective,
+    OMPTargetParallelForDirective = clang_sys::CXCursor_OMPTargetParallelForDirective,
+    OMPTargetUpdateDirective = clang_sys::CXCursor_OMPTargetUpdateDirective,
+    OMPDistributeParallelForDirective = clang_sys::CXCursor_OMPDistributeParallelForDirective,
+    OMPDistributeParallelForSimdDirective =
+        clang_sys::CXCursor_OMPDistributeParallelForSimdDirective,
+    OMPDistributeSimdDirective = clang_sys::CXCursor_OMPDistributeSimdDirective,
+    OMPTargetParallelForSimdDirective = clang_sys::CXCursor_OMPTargetParallelForSimdDirective,
+    OMPTargetSimdDirective = clang_sys::CXCursor_OMPTargetSimdDirective,
+    OMPTeamsDistributeDirective = clang_sys::CXCursor_OMPTeamsDistributeDirective,
+    OMPTeamsDistributeSimdDirective = clang_sys::CXCursor_OMPTeamsDistributeSimdDirective,
+    OMPTeamsDistributeParallelForSimdDirective =
+        clang_sys::CXCursor_OMPTeamsDistributeParallelForSimdDirective,
+    OMPTeamsDistributeParallelForDirective =
+        clang_sys::CXCursor_OMPTeamsDistributeParallelForDirective,
+    OMPTargetTeamsDirective = clang_sys::CXCursor_OMPTargetTeamsDirective,
+    OMPTargetTeamsDistributeDirective = clang_sys::CXCursor_OMPTargetTeamsDistributeDirective,
+    OMPTargetTeamsDistributeParallelForDirective =
+        clang_sys::CXCursor_OMPTargetTeamsDistributeParallelForDirective,
+    OMPTargetTeamsDistributeParallelForSimdDirective =
+        clang_sys::CXCursor_OMPTargetTeamsDistributeParallelForSimdDirective,
+    OMPTargetTeamsDistributeSimdDirective =
+        clang_sys::CXCursor_OMPTargetTeamsDistributeSimdDirective,
+    BuiltinBitCastExpr = clang_sys::CXCursor_BuiltinBitCastExpr,
+    OMPMasterTaskLoopDirective = clang_sys::CXCursor_OMPMasterTaskLoopDirective,
+    OMPParallelMasterTaskLoopDirective = clang_sys::CXCursor_OMPParallelMasterTaskLoopDirective,
+    OMPMasterTaskLoopSimdDirective = clang_sys::CXCursor_OMPMasterTaskLoopSimdDirective,
+    OMPParallelMasterTaskLoopSimdDirective =
+        clang_sys::CXCursor_OMPParallelMasterTaskLoopSimdDirective,
+    OMPParallelMasterDirective = clang_sys::CXCursor_OMPParallelMasterDirective,
+    OMPDepobjDirective = clang_sys::CXCursor_OMPDepobjDirective,
+    OMPScanDirective = clang_sys::CXCursor_OMPScanDirective,
+    OMPTileDirective = clang_sys::CXCursor_OMPTileDirective,
+    OMPCanonicalLoop = clang_sys::CXCursor_OMPCanonicalLoop,
+    OMPInteropDirective = clang_sys::CXCursor_OMPInteropDirective,
+    OMPDispatchDirective = clang_sys::CXCursor_OMPDispatchDirective,
+    OMPMaskedDirective = clang_sys::CXCursor_OMPMaskedDirective,
+    OMPUnrollDirective = clang_sys::CXCursor_OMPUnrollDirective,
+    OMPMetaDirective = clang_sys::CXCursor_OMPMetaDirective,
+    OMPGenericLoopDirective = clang_sys::CXCursor_OMPGenericLoopDirective,
+    OMPTeamsGenericLoopDirective = clang_sys::CXCursor_OMPTeamsGenericLoopDirective,
+    OMPTargetTeamsGenericLoopDirective = clang_sys::CXCursor_OMPTargetTeamsGenericLoopDirective,
+    OMPParallelGenericLoopDirective = clang_sys::CXCursor_OMPParallelGenericLoopDirective,
+    OMPTargetParallelGenericLoopDirective =
+        clang_sys::CXCursor_OMPTargetParallelGenericLoopDirective,
+    OMPParallelMaskedDirective = clang_sys::CXCursor_OMPParallelMaskedDirective,
+    OMPMaskedTaskLoopDirective = clang_sys::CXCursor_OMPMaskedTaskLoopDirective,
+    OMPMaskedTaskLoopSimdDirective = clang_sys::CXCursor_OMPMaskedTaskLoopSimdDirective,
+    OMPParallelMaskedTaskLoopDirective = clang_sys::CXCursor_OMPParallelMaskedTaskLoopDirective,
+    OMPParallelMaskedTaskLoopSimdDirective =
+        clang_sys::CXCursor_OMPParallelMaskedTaskLoopSimdDirective,
+    OMPErrorDirective = clang_sys::CXCursor_OMPErrorDirective,
+    OMPScopeDirective = clang_sys::CXCursor_OMPScopeDirective,
+    OMPReverseDirective = clang_sys::CXCursor_OMPReverseDirective,
+    OMPInterchangeDirective = clang_sys::CXCursor_OMPInterchangeDirective,
+    OMPAssumeDirective = clang_sys::CXCursor_OMPAssumeDirective,
+    OpenACCComputeConstruct = clang_sys::CXCursor_OpenACCComputeConstruct,
+    OpenACCLoopConstruct = clang_sys::CXCursor_OpenACCLoopConstruct,
+    OpenACCCombinedConstruct = clang_sys::CXCursor_OpenACCCombinedConstruct,
+    OpenACCDataConstruct = clang_sys::CXCursor_OpenACCDataConstruct,
+    OpenACCEnterDataConstruct = clang_sys::CXCursor_OpenACCEnterDataConstruct,
+    OpenACCExitDataConstruct = clang_sys::CXCursor_OpenACCExitDataConstruct,
+    OpenACCHostDataConstruct = clang_sys::CXCursor_OpenACCHostDataConstruct,
+    OpenACCWaitConstruct = clang_sys::CXCursor_OpenACCWaitConstruct,
+    OpenACCInitConstruct = clang_sys::CXCursor_OpenACCInitConstruct,
+    OpenACCShutdownConstruct = clang_sys::CXCursor_OpenACCShutdownConstruct,
+    OpenACCSetConstruct = clang_sys::CXCursor_OpenACCSetConstruct,
+    OpenACCUpdateConstruct = clang_sys::CXCursor_OpenACCUpdateConstruct,
+    TranslationUnit = clang_sys::CXCursor_TranslationUnit,
+    UnexposedAttr = clang_sys::CXCursor_UnexposedAttr,
+    IBActionAttr = clang_sys::CXCursor_IBActionAttr,
+    IBOutletAttr = clang_sys::CXCursor_IBOutletAttr,
+    IBOutletCollectionAttr = clang_sys::CXCursor_IBOutletCollectionAttr,
+    CXXFinalAttr = clang_sys::CXCursor_CXXFinalAttr,
+    CXXOverrideAttr = clang_sys::CXCursor_CXXOverrideAttr,
+    AnnotateAttr = clang_sys::CXCursor_AnnotateAttr,
+    AsmLabelAttr = clang_sys::CXCursor_AsmLabelAttr,
+    PackedAttr = clang_sys::CXCursor_PackedAttr,
+    PureAttr = clang_sys::CXCursor_PureAttr,
+    ConstAttr = clang_sys::CXCursor_ConstAttr,
+    NoDuplicateAttr = clang_sys::CXCursor_NoDuplicateAttr,
+    CUDAConstantAttr = clang_sys::CXCursor_CUDAConstantAttr,
+    CUDADeviceAttr = clang_sys::CXCursor_CUDADeviceAttr,
+    CUDAGlobalAttr = clang_sys::CXCursor_CUDAGlobalAttr,
+    CUDAHostAttr = clang_sys::CXCursor_CUDAHostAttr,
+    CUDASharedAttr = clang_sys::CXCursor_CUDASharedAttr,
+    VisibilityAttr = clang_sys::CXCursor_VisibilityAttr,
+    DLLExport = clang_sys::CXCursor_DLLExport,
+    DLLImport = clang_sys::CXCursor_DLLImport,
+    NSReturnsRetained = clang_sys::CXCursor_NSReturnsRetained,
+    NSReturnsNotRetained = clang_sys::CXCursor_NSReturnsNotRetained,
+    NSReturnsAutoreleased = clang_sys::CXCursor_NSReturnsAutoreleased,
+    NSConsumesSelf = clang_sys::CXCursor_NSConsumesSelf,
+    NSConsumed = clang_sys::CXCursor_NSConsumed,
+    ObjCException = clang_sys::CXCursor_ObjCException,
+    ObjCNSObject = clang_sys::CXCursor_ObjCNSObject,
+    ObjCIndependentClass = clang_sys::CXCursor_ObjCIndependentClass,
+    ObjCPreciseLifetime = clang_sys::CXCursor_ObjCPreciseLifetime,
+    ObjCReturnsInnerPointer = clang_sys::CXCursor_ObjCReturnsInnerPointer,
+    ObjCRequiresSuper = clang_sys::CXCursor_ObjCRequiresSuper,
+    ObjCRootClass = clang_sys::CXCursor_ObjCRootClass,
+    ObjCSubclassingRestricted = clang_sys::CXCursor_ObjCSubclassingRestricted,
+    ObjCExplicitProtocolImpl = clang_sys::CXCursor_ObjCExplicitProtocolImpl,
+    ObjCDesignatedInitializer = clang_sys::CXCursor_ObjCDesignatedInitializer,
+    ObjCRuntimeVisible = clang_sys::CXCursor_ObjCRuntimeVisible,
+    ObjCBoxable = clang_sys::CXCursor_ObjCBoxable,
+    FlagEnum = clang_sys::CXCursor_FlagEnum,
+    ConvergentAttr = clang_sys::CXCursor_ConvergentAttr,
+    WarnUnusedAttr = clang_sys::CXCursor_WarnUnusedAttr,
+    WarnUnusedResultAttr = clang_sys::CXCursor_WarnUnusedResultAttr,
+    AlignedAttr = clang_sys::CXCursor_AlignedAttr,
+    PreprocessingDirective = clang_sys::CXCursor_PreprocessingDirective,
+    MacroDefinition = clang_sys::CXCursor_MacroDefinition,
+    MacroExpansion = clang_sys::CXCursor_MacroExpansion,
+    InclusionDirective = clang_sys::CXCursor_InclusionDirective,
+    ModuleImportDecl = clang_sys::CXCursor_ModuleImportDecl,
+    TypeAliasTemplateDecl = clang_sys::CXCursor_TypeAliasTemplateDecl,
+    StaticAssert = clang_sys::CXCursor_StaticAssert,
+    FriendDecl = clang_sys::CXCursor_FriendDecl,
+    ConceptDecl = clang_sys::CXCursor_ConceptDecl,
+    OverloadCandidate = clang_sys::CXCursor_OverloadCandidate,
+    /// a kind added to libclang after this crate, with its raw `CXCursorKind` value
+    Unknown(i32),
+}
+
+impl CursorKind {
+    pub(crate) fn from_raw(raw: clang_sys::CXCursorKind) -> Self {
+        match raw {
+            clang_sys::CXCursor_UnexposedDecl => Self::UnexposedDecl,
+            clang_sys::CXCursor_StructDecl => Self::StructDecl,
+            clang_sys::CXCursor_UnionDecl => Self::UnionDecl,
+            clang_sys::CXCursor_ClassDecl => Self::ClassDecl,
+            clang_sys::CXCursor_EnumDecl => Self::EnumDecl,
+            clang_sys::CXCursor_FieldDecl => Self::FieldDecl,
+            clang_sys::CXCursor_EnumConstantDecl => Self::EnumConstantDecl,
+            clang_sys::CXCursor_FunctionDecl => Self::FunctionDecl,
+            clang_sys::CXCursor_VarDecl => Self::VarDecl,
+            clang_sys::CXCursor_ParmDecl => Self::ParmDecl,
+            clang_sys::CXCursor_ObjCInterfaceDecl => Self::ObjCInterfaceDecl,
+            clang_sys::CXCursor_ObjCCategoryDecl => Self::ObjCCategoryDecl,
+            clang_sys::CXCursor_ObjCProtocolDecl => Self::ObjCProtocolDecl,
+            clang_sys::CXCursor_ObjCPropertyDecl => Self::ObjCPropertyDecl,
+            clang_sys::CXCursor_ObjCIvarDecl => Self::ObjCIvarDecl,
+            clang_sys::CXCursor_ObjCInstanceMethodDecl => Self::ObjCInstanceMethodDecl,
+            clang_sys::CXCursor_ObjCClassMethodDecl => Self::ObjCClassMethodDecl,
+            clang_sys::CXCursor_ObjCImplementationDecl => Self::ObjCImplementationDecl,
+            clang_sys::CXCursor_ObjCCategoryImplDecl => Self::ObjCCategoryImplDecl,
+            clang_sys::CXCursor_TypedefDecl => Self::TypedefDecl,
+            clang_sys::CXCursor_CXXMethod => Self::CXXMethod,
+            clang_sys::CXCursor_Namespace => Self::Namespace,
+            clang_sys::CXCursor_LinkageSpec => Self::LinkageSpec,
+            clang_sys::CXCursor_Constructor => Self::Constructor,
+            clang_sys::CXCursor_Destructor => Self::Destructor,
+            clang_sys::CXCursor_ConversionFunction => Self::ConversionFunction,
+            clang_sys::CXCursor_TemplateTypeParameter => Self::TemplateTypeParameter,
+            clang_sys::CXCursor_NonTypeTemplateParameter => Self::NonTypeTemplateParameter,
+            clang_sys::CXCursor_TemplateTemplateParameter => Self::TemplateTemplateParameter,
+            clang_sys::CXCursor_FunctionTemplate => Self::FunctionTemplate,
+            clang_sys::CXCursor_ClassTemplate => Self::ClassTemplate,
+            clang_sys::CXCursor_ClassTemplatePartialSpecialization => {
+                Self::ClassTemplatePartialSpecialization
+            }
+            clang_sys::CXCursor_NamespaceAlias => Self::NamespaceAlias,
+            clang_sys::CXCursor_UsingDirective => Self::UsingDirective,
+            clang_sys::CXCursor_UsingDeclaration => Self::UsingDeclaration,
+            clang_sys::CXCursor_TypeAliasDecl => Self::TypeAliasDecl,
+            clang_sys::CXCursor_ObjCSynthesizeDecl => Self::ObjCSynthesizeDecl,
+            clang_sys::CXCursor_ObjCDynamicDecl => Self::ObjCDynamicDecl,
+            clang_sys::CXCursor_CXXAccessSpecifier => Self::CXXAccessSpecifier,
+            clang_sys::CXCursor_ObjCSuperClassRef => Self::ObjCSuperClassRef,
+            clang_sys::CXCursor_ObjCProtocolRef => Self::ObjCProtocolRef,
+            clang_sys::CXCursor_ObjCClassRef => Self::ObjCClassRef,
+            clang_sys::CXCursor_TypeRef => Self::TypeRef,
+            clang_sys::CXCursor_CXXBaseSpecifier => Self::CXXBaseSpecifier,
+            clang_sys::CXCursor_TemplateRef => Self::TemplateRef,
+            clang_sys::CXCursor_NamespaceRef => Self::NamespaceRef,
+            clang_sys::CXCursor_MemberRef => Self::MemberRef,
+            clang_sys::CXCursor_LabelRef => Self::LabelRef,
+            clang_sys::CXCursor_OverloadedDeclRef => Self::OverloadedDeclRef,
+            clang_sys::CXCursor_VariableRef => Self::VariableRef,
+            clang_sys::CXCursor_InvalidFile => Self::InvalidFile,
+            clang_sys::CXCursor_NoDeclFound => Self::NoDeclFound,
+            clang_sys::CXCursor_NotImplemented => Self::NotImplemented,
+            clang_sys::CXCursor_InvalidCode => Self::InvalidCode,
+            clang_sys::CXCursor_UnexposedExpr => Self::UnexposedExpr,
+            clang_sys::CXCursor_DeclRefExpr => Self::DeclRefExpr,
+            clang_sys::CXCursor_MemberRefExpr => Self::MemberRefExpr,
+            clang_sys::CXCursor_CallExpr => Self::CallExpr,
+            clang_sys::CXCursor_ObjCMessageExpr => Self::ObjCMessageExpr,
+            clang_sys::CXCursor_BlockExpr => Self::BlockExpr,
+            clang_sys::CXCursor_IntegerLiteral => Self::IntegerLiteral,
+            clang_sys::CXCursor_FloatingLiteral => Self::FloatingLiteral,
+            clang_sys::CXCursor_ImaginaryLiteral => Self::ImaginaryLiteral,
+            clang_sys::CXCursor_StringLiteral => Self::StringLiteral,
+            clang_sys::CXCursor_CharacterLiteral => Self::CharacterLiteral,
+            clang_sys::CXCursor_ParenExpr => Self::ParenExpr,
+            clang_sys::CXCursor_UnaryOperator => Self::UnaryOperator,
+            clang_sys::CXCursor_ArraySubscriptExpr => Self::ArraySubscriptExpr,
+            clang_sys::CXCursor_BinaryOperator => Self::BinaryOperator,
+            clang_sys::CXCursor_CompoundAssignOperator => Self::CompoundAssignOperator,
+            clang_sys::CXCursor_ConditionalOperator => Self::ConditionalOperator,
+            clang_sys::CXCursor_CStyleCastExpr => Self::CStyleCastExpr,
+            clang_sys::CXCursor_CompoundLiteralExpr => Self::CompoundLiteralExpr,
+            clang_sys::CXCursor_InitListExpr => Self::InitListExpr,
+            clang_sys::CXCursor_AddrLabelExpr => Self::AddrLabelExpr,
+            clang_sys::CXCursor_StmtExpr => Self::StmtExpr,
+            clang_sys::CXCursor_GenericSelectionExpr => Self::GenericSelectionExpr,
+            clang_sys::CXCursor_GNUNullExpr => Self::GNUNullExpr,
+            clang_sys::CXCursor_CXXStaticCastExpr => Self::CXXStaticCastExpr,
+            clang_sys::CXCursor_CXXDynamicCastExpr => Self::CXXDynamicCastExpr,
+            clang_sys::CXCursor_CXXReinterpretCastExpr => Self::CXXReinterpretCastExpr,
+            clang_sys::CXCursor_CXXConstCastExpr => Self::CXXConstCastExpr,
+            clang_sys::CXCursor_CXXFunctionalCastExpr => Self::CXXFunctionalCastExpr,
+            clang_sys::CXCursor_CXXTypeidExpr => Self::CXXTypeidExpr,
+            clang_sys::CXCursor_CXXBoolLiteralExpr => Self::CXXBoolLiteralExpr,
+            clang_sys::CXCursor_CXXNullPtrLiteralExpr => Self::CXXNullPtrLiteralExpr,
+            clang_sys::CXCursor_CXXThisExpr => Self::CXXThisExpr,
+            clang_sys::CXCursor_CXXThrowExpr => Self::CXXThrowExpr,
+            clang_sys::CXCursor_CXXNewExpr => Self::CXXNewExpr,
+            clang_sys::CXCursor_CXXDeleteExpr => Self::CXXDeleteExpr,
+            clang_sys::CXCursor_UnaryExpr => Self::UnaryExpr,
+            clang_sys::CXCursor_ObjCStringLiteral => Self::ObjCStringLiteral,
+            clang_sys::CXCursor_ObjCEncodeExpr => Self::ObjCEncodeExpr,
+            clang_sys::CXCursor_ObjCSelectorExpr => Self::ObjCSelectorExpr,
+            clang_sys::CXCursor_ObjCProtocolExpr => Self::ObjCProtocolExpr,
+            clang_sys::CXCursor_ObjCBridgedCastExpr => Self::ObjCBridgedCastExpr,
+            clang_sys::CXCursor_PackExpansionExpr => Self::PackExpansionExpr,
+            clang_sys::CXCursor_SizeOfPackExpr => Self::SizeOfPackExpr,
+            clang_sys::CXCursor_LambdaExpr => Self::LambdaExpr,
+            clang_sys::CXCursor_ObjCBoolLiteralExpr => Self::ObjCBoolLiteralExpr,
+            clang_sys::CXCursor_ObjCSelfExpr => Self::ObjCSelfExpr,
+            clang_sys::CXCursor_ArraySectionExpr => Self::ArraySectionExpr,
+            clang_sys::CXCursor_ObjCAvailabilityCheckExpr => Self::ObjCAvailabilityCheckExpr,
+            clang_sys::CXCursor_FixedPointLiteral => Self::FixedPointLiteral,
+            clang_sys::CXCursor_OMPArrayShapingExpr => Self::OMPArrayShapingExpr,
+            clang_sys::CXCursor_OMPIteratorExpr => Self::OMPIteratorExpr,
+            clang_sys::CXCursor_CXXAddrspaceCastExpr => Self::CXXAddrspaceCastExpr,
+            clang_sys::CXCursor_ConceptSpecializationExpr => Self::ConceptSpecializationExpr,
+            clang_sys::CXCursor_RequiresExpr => Self::RequiresExpr,
+            clang_sys::CXCursor_CXXParenListInitExpr => Self::CXXParenListInitExpr,
+            clang_sys::CXCursor_PackIndexingExpr => Self::PackIndexingExpr,
+            clang_sys::CXCursor_UnexposedStmt => Self::UnexposedStmt,
+            clang_sys::CXCursor_LabelStmt => Self::LabelStmt,
+            clang_sys::CXCursor_CompoundStmt => Self::CompoundStmt,
+            clang_sys::CXCursor_CaseStmt => Self::CaseStmt,
+            clang_sys::CXCursor_DefaultStmt => Self::DefaultStmt,
+            clang_sys::CXCursor_IfStmt => Self::IfStmt,
+            clang_sys::CXCursor_SwitchStmt => Self::SwitchStmt,
+            clang_sys::CXCursor_WhileStmt => Self::WhileStmt,
+            clang_sys::CXCursor_DoStmt => Self::DoStmt,
+            clang_sys::CXCursor_ForStmt => Self::ForStmt,
+            clang_sys::CXCursor_GotoStmt => Self::GotoStmt,
+            clang_sys::CXCursor_IndirectGotoStmt => Self::IndirectGotoStmt,
+            clang_sys::CXCursor_ContinueStmt => Self::ContinueStmt,
+            clang_sys::CXCursor_BreakStmt => Self::BreakStmt,
+            clang_sys::CXCursor_ReturnStmt => Self::ReturnStmt,
+            clang_sys::CXCursor_AsmStmt => Self::AsmStmt,
+            clang_sys::CXCursor_ObjCAtTryStmt => Self::ObjCAtTryStmt,
+            clang_sys::CXCursor_ObjCAtCatchStmt => Self::ObjCAtCatchStmt,
+            clang_sys::CXCursor_ObjCAtFinallyStmt => Self::ObjCAtFinallyStmt,
+            clang_sys::CXCursor_ObjCAtThrowStmt => Self::ObjCAtThrowStmt,
+            clang_sys::CXCursor_ObjCAtSynchronizedStmt => Self::ObjCAtSynchronizedStmt,
+            clang_sys::CXCursor_ObjCAutoreleasePoolStmt => Self::ObjCAutoreleasePoolStmt,
+            clang_sys::CXCursor_ObjCForCollectionStmt => Self::ObjCForCollectionStmt,
+            clang_sys::CXCursor_CXXCatchStmt => Self::CXXCatchStmt,
+            clang_sys::CXCursor_CXXTryStmt => Self::CXXTryStmt,
+            clang_sys::CXCursor_CXXForRangeStmt => Self::CXXForRangeStmt,
+            clang_sys::CXCursor_SEHTryStmt => Self::SEHTryStmt,
+            clang_sys::CXCursor_SEHExceptStmt => Self::SEHExceptStmt,
+            clang_sys::CXCursor_SEHFinallyStmt => Self::SEHFinallyStmt,
+            clang_sys::CXCursor_MSAsmStmt => Self::MSAsmStmt,
+            clang_sys::CXCursor_NullStmt => Self::NullStmt,
+            clang_sys::CXCursor_DeclStmt => Self::DeclStmt,
+            clang_sys::CXCursor_OMPParallelDirective => Self::OMPParallelDirective,
+            clang_sys::CXCursor_OMPSimdDirective => Self::OMPSimdDirective,
+            clang_sys::CXCursor_OMPForDirective => Self::OMPForDirective,
+            clang_sys::CXCursor_OMPSectionsDirective => Self::OMPSectionsDirective,
+            clang_sys::CXCursor_OMPSectionDirective => Self::OMPSectionDirective,
+            clang_sys::CXCursor_OMPSingleDirective => Self::OMPSingleDirective,
+            clang_sys::CXCursor_OMPParallelForDirective => Self::OMPParallelForDirective,
+            clang_sys::CXCursor_OMPParallelSectionsDirective => Self::OMPParallelSectionsDirective,
+            clang_sys::CXCursor_OMPTaskDirective => Self::OMPTaskDirective,
+            clang_sys::CXCursor_OMPMasterDirective => Self::OMPMasterDirective,
+            clang_sys::CXCursor_OMPCriticalDirective => Self::OMPCriticalDirective,
+            clang_sys::CXCursor_OMPTaskyieldDirective => Self::OMPTaskyieldDirective,
+            clang_sys::CXCursor_OMPBarrierDirective => Self::OMPBarrierDirective,
+            clang_sys::CXCursor_OMPTaskwaitDirective => Self::OMPTaskwaitDirective,
+            clang_sys::CXCursor_OMPFlushDirective => Self::OMPFlushDirective,
+            clang_sys::CXCursor_SEHLeaveStmt => Self::SEHLeaveStmt,
+            clang_sys::CXCursor_OMPOrderedDirective => Self::OMPOrderedDirective,
+            clang_sys::CXCursor_OMPAtomicDirective => Self::OMPAtomicDirective,
+            clang_sys::CXCursor_OMPForSimdDirective => Self::OMPForSimdDirective,
+            clang_sys::CXCursor_OMPParallelForSimdDirective => Self::OMPParallelForSimdDirective,
+            clang_sys::CXCursor_OMPTargetDirective => Self::OMPTargetDirective,
+            clang_sys::CXCursor_OMPTeamsDirective => Self::OMPTeamsDirective,
+            clang_sys::CXCursor_OMPTaskgroupDirective => Self::OMPTaskgroupDirective,
+            clang_sys::CXCursor_OMPCancellationPointDirective => {
+                Self::OMPCancellationPointDirective
+            }
+            clang_sys::CXCursor_OMPCancelDirective => Self::OMPCancelDirective,
+            clang_sys::CXCursor_OMPTargetDataDirective => Self::OMPTargetDataDirective,
+            clang_sys::CXCursor_OMPTaskLoopDirective => Self::OMPTaskLoopDirective,
+            clang_sys::CXCursor_OMPTaskLoopSimdDirective => Self::OMPTaskLoopSimdDirective,
+            clang_sys::CXCursor_OMPDistributeDirective => Self::OMPDistributeDirective,
+            clang_sys::CXCursor_OMPTargetEnterDataDirective => Self::OMPTargetEnterDataDirective,
+            clang_sys::CXCursor_OMPTargetExitDataDirective => Self::OMPTargetExitDataDirective,
+            clang_sys::CXCursor_OMPTargetParallelDirective => Self::OMPTargetParallelDirective,
+            clang_sys::CXCursor_OMPTargetParallelForDirective => {
+                Self::OMPTargetParallelForDirective
+            }
+            clang_sys::CXCursor_OMPTargetUpdateDirective => Self::OMPTargetUpdateDirective,
+            clang_sys::CXCursor_OMPDistributeParallelForDirective => {
+                Self::OMPDistributeParallelForDirective
+            }
+            clang_sys::CXCursor_OMPDistributeParallelForSimdDirective => {
+                Self::OMPDistributeParallelForSimdDirective
+            }
+            clang_sys::CXCursor_OMPDistributeSimdDirective => Self::OMPDistributeSimdDirective,
+            clang_sys::CXCursor_OMPTargetParallelForSimdDirective => {
+                Self::OMPTargetParallelForSimdDirective
+            }
+            clang_sys::CXCursor_OMPTargetSimdDirective => Self::OMPTargetSimdDirective,
+            clang_sys::CXCursor_OMPTeamsDistributeDirective => Self::OMPTeamsDistributeDirective,
+            clang_sys::CXCursor_OMPTeamsDistributeSimdDirective => {
+                Self::OMPTeamsDistributeSimdDirective
+            }
+            clang_sys::CXCursor_OMPTeamsDistributeParallelForSimdDirective => {
+                Self::OMPTeamsDistributeParallelForSimdDirective
+            }
+            clang_sys::CXCursor_OMPTeamsDistributeParallelForDirective => {
+                Self::OMPTeamsDistributeParallelForDirective
+            }
+            clang_sys::CXCursor_OMPTargetTeamsDirective => Self::OMPTargetTeamsDirective,
+            clang_sys::CXCursor_OMPTargetTeamsDistributeDirective => {
+                Self::OMPTargetTeamsDistributeDirective
+            }
+            clang_sys::CXCursor_OMPTargetTeamsDistributeParallelForDirective => {
+                Self::OMPTargetTeamsDistributeParallelForDirective
+            }
+            clang_sys::CXCursor_OMPTargetTeamsDistributeParallelForSimdDirective => {
+                Self::OMPTargetTeamsDistributeParallelForSimdDirective
+            }
+            clang_sys::CXCursor_OMPTargetTeamsDistributeSimdDirective => {
+                Self::OMPTargetTeamsDistributeSimdDirective
+            }
+            clang_sys::CXCursor_BuiltinBitCastExpr => Self::BuiltinBitCastExpr,
+            clang_sys::CXCursor_OMPMasterTaskLoopDirective => Self::OMPMasterTaskLoopDirective,
+            clang_sys::CXCursor_OMPParallelMasterTaskLoopDirective => {
+                Self::OMPParallelMasterTaskLoopDirective
+            }
+            clang_sys::CXCursor_OMPMasterTaskLoopSimdDirective => {
+                Self::OMPMasterTaskLoopSimdDirective
+            }
+            clang_sys::CXCursor_OMPParallelMasterTaskLoopSimdDirective => {
+                Self::OMPParallelMasterTaskLoopSimdDirective
+            }
+            clang_sys::CXCursor_OMPParallelMasterDirective => Self::OMPParallelMasterDirective,
+            clang_sys::CXCursor_OMPDepobjDirective => Self::OMPDepobjDirective,
+            clang_sys::CXCursor_OMPScanDirective => Self::OMPScanDirective,
+            clang_sys::CXCursor_OMPTileDirective => Self::OMPTileDirective,
+            clang_sys::CXCursor_OMPCanonicalLoop => Self::OMPCanonicalLoop,
+            clang_sys::CXCursor_OMPInteropDirective => Self::OMPInteropDirective,
+            clang_sys::CXCursor_OMPDispatchDirective => Self::OMPDispatchDirective,
+            clang_sys::CXCursor_OMPMaskedDirective => Self::OMPMaskedDirective,
+            clang_sys::CXCursor_OMPUnrollDirective => Self::OMPUnrollDirective,
+            clang_sys::CXCursor_OMPMetaDirective => Self::OMPMetaDirective,
+            clang_sys::CXCursor_OMPGenericLoopDirective => Self::OMPGenericLoopDirective,
+            clang_sys::CXCursor_OMPTeamsGenericLoopDirective => Self::OMPTeamsGenericLoopDirective,
+            clang_sys::CXCursor_OMPTargetTeamsGenericLoopDirective => {
+                Self::OMPTargetTeamsGenericLoopDirective
+            }
+            clang_sys::CXCursor_OMPParallelGenericLoopDirective => {
+                Self::OMPParallelGenericLoopDirective
+            }
+            clang_sys::CXCursor_OMPTargetParallelGenericLoopDirective => {
+                Self::OMPTargetParallelGenericLoopDirective
+            }
+            clang_sys::CXCursor_OMPParallelMaskedDirective => Self::OMPParallelMaskedDirective,
+            clang_sys::CXCursor_OMPMaskedTaskLoopDirective => Self::OMPMaskedTaskLoopDirective,
+            clang_sys::CXCursor_OMPMaskedTaskLoopSimdDirective => {
+                Self::OMPMaskedTaskLoopSimdDirective
+            }
+            clang_sys::CXCursor_OMPParallelMaskedTaskLoopDirective => {
+                Self::OMPParallelMaskedTaskLoopDirective
+            }
+            clang_sys::CXCursor_OMPParallelMaskedTaskLoopSimdDirective => {
+                Self::OMPParallelMaskedTaskLoopSimdDirective
+            }
+            clang_sys::CXCursor_OMPErrorDirective => Self::OMPErrorDirective,
+            clang_sys::CXCursor_OMPScopeDirective => Self::OMPScopeDirective,
+            clang_sys::CXCursor_OMPReverseDirective => Self::OMPReverseDirective,
+            clang_sys::CXCursor_OMPInterchangeDirective => Self::OMPInterchangeDirective,
+            clang_sys::CXCursor_OMPAssumeDirective => Self::OMPAssumeDirective,
+            clang_sys::CXCursor_OpenACCComputeConstruct => Self::OpenACCComputeConstruct,
+            clang_sys::CXCursor_OpenACCLoopConstruct => Self::OpenACCLoopConstruct,
+            clang_sys::CXCursor_OpenACCCombinedConstruct => Self::OpenACCCombinedConstruct,
+            clang_sys::CXCursor_OpenACCDataConstruct => Self::OpenACCDataConstruct,
+            clang_sys::CXCursor_OpenACCEnterDataConstruct => Self::OpenACCEnterDataConstruct,
+            clang_sys::CXCursor_OpenACCExitDataConstruct => Self::OpenACCExitDataConstruct,
+            clang_sys::CXCursor_OpenACCHostDataConstruct => Self::OpenACCHostDataConstruct,
+            clang_sys::CXCursor_OpenACCWaitConstruct => Self::OpenACCWaitConstruct,
+            clang_sys::CXCursor_OpenACCInitConstruct => Self::OpenACCInitConstruct,
+            clang_sys::CXCursor_OpenACCShutdownConstruct => Self::OpenACCShutdownConstruct,
+            clang_sys::CXCursor_OpenACCSetConstruct => Self::OpenACCSetConstruct,
+            clang_sys::CXCursor_OpenACCUpdateConstruct => Self::OpenACCUpdateConstruct,
+            clang_sys::CXCursor_TranslationUnit => Self::TranslationUnit,
+            clang_sys::CXCursor_UnexposedAttr => Self::UnexposedAttr,
+            clang_sys::CXCursor_IBActionAttr => Self::IBActionAttr,
+            clang_sys::CXCursor_IBOutletAttr => Self::IBOutletAttr,
+            clang_sys::CXCursor_IBOutletCollectionAttr => Self::IBOutletCollectionAttr,
+            clang_sys::CXCursor_CXXFinalAttr => Self::CXXFinalAttr,
+            clang_sys::CXCursor_CXXOverrideAttr => Self::CXXOverrideAttr,
+            clang_sys::CXCursor_AnnotateAttr => Self::AnnotateAttr,
+            clang_sys::CXCursor_AsmLabelAttr => Self::AsmLabelAttr,
+            clang_sys::CXCursor_PackedAttr => Self::PackedAttr,
+            clang_sys::CXCursor_PureAttr => Self::PureAttr,
+            clang_sys::CXCursor_ConstAttr => Self::ConstAttr,
+            clang_sys::CXCursor_NoDuplicateAttr => Self::NoDuplicateAttr,
+            clang_sys::CXCursor_CUDAConstantAttr => Self::CUDAConstantAttr,
+            clang_sys::CXCursor_CUDADeviceAttr => Self::CUDADeviceAttr,
+            clang_sys::CXCursor_CUDAGlobalAttr => Self::CUDAGlobalAttr,
+            clang_sys::CXCursor_CUDAHostAttr => Self::CUDAHostAttr,
+            clang_sys::CXCursor_CUDASharedAttr => Self::CUDASharedAttr,
+            clang_sys::CXCursor_VisibilityAttr => Self::VisibilityAttr,
+            clang_sys::CXCursor_DLLExport => Self::DLLExport,
+            clang_sys::CXCursor_DLLImport => Self::DLLImport,
+            clang_sys::CXCursor_NSReturnsRetained => Self::NSReturnsRetained,
+            clang_sys::CXCursor_NSReturnsNotRetained => Self::NSReturnsNotRetained,
+            clang_sys::CXCursor_NSReturnsAutoreleased => Self::NSReturnsAutoreleased,
+            clang_sys::CXCursor_NSConsumesSelf => Self::NSConsumesSelf,
+            clang_sys::CXCursor_NSConsumed => Self::NSConsumed,
+            clang_sys::CXCursor_ObjCException => Self::ObjCException,
+            clang_sys::CXCursor_ObjCNSObject => Self::ObjCNSObject,
+            clang_sys::CXCursor_ObjCIndependentClass => Self::ObjCIndependentClass,
+            clang_sys::CXCursor_ObjCPreciseLifetime => Self::ObjCPreciseLifetime,
+            clang_sys::CXCursor_ObjCReturnsInnerPointer => Self::ObjCReturnsInnerPointer,
+            clang_sys::CXCursor_ObjCRequiresSuper => Self::ObjCRequiresSuper,
+            clang_sys::CXCursor_ObjCRootClass => Self::ObjCRootClass,
+            clang_sys::CXCursor_ObjCSubclassingRestricted => Self::ObjCSubclassingRestricted,
+            clang_sys::CXCursor_ObjCExplicitProtocolImpl => Self::ObjCExplicitProtocolImpl,
+            clang_sys::CXCursor_ObjCDesignatedInitializer => Self::ObjCDesignatedInitializer,
+            clang_sys::CXCursor_ObjCRuntimeVisible => Self::ObjCRuntimeVisible,
+            clang_sys::CXCursor_ObjCBoxable => Self::ObjCBoxable,
+            clang_sys::CXCursor_FlagEnum => Self::FlagEnum,
+            clang_sys::CXCursor_ConvergentAttr => Self::ConvergentAttr,
+            clang_sys::CXCursor_WarnUnusedAttr => Self::WarnUnusedAttr,
+            clang_sys::CXCursor_WarnUnusedResultAttr => Self::WarnUnusedResultAttr,
+            clang_sys::CXCursor_AlignedAttr => Self::AlignedAttr,
+            clang_sys::CXCursor_PreprocessingDirective => Self::PreprocessingDirective,
+            clang_sys::CXCursor_MacroDefinition => Self::MacroDefinition,
+            clang_sys::CXCursor_MacroExpansion => Self::MacroExpansion,
+            clang_sys::CXCursor_InclusionDirective => Self::InclusionDirective,
+            clang_sys::CXCursor_ModuleImportDecl => Self::ModuleImportDecl,
+            clang_sys::CXCursor_TypeAliasTemplateDecl => Self::TypeAliasTemplateDecl,
+            clang_sys::CXCursor_StaticAssert => Self::StaticAssert,
+            clang_sys::CXCursor_FriendDecl => Self::FriendDecl,
+            clang_sys::CXCursor_ConceptDecl => Self::ConceptDecl,
+            clang_sys::CXCursor_OverloadCandidate => Self::OverloadCandidate,
+            k => Self::Unknown(k),
+        }
+    }
+    pub(crate) fn to_raw(self) -> clang_sys::CXCursorKind {
+        match self {
+            Self::Unknown(raw) => raw,
+            // a `#[repr(i32)]` enum starts with its discriminant
+            _ => unsafe { *(&self as *const Self as *const clang_sys::CXCursorKind) },
+        }
+    }
+
+    pub fn spelling(&self) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_getCursorKindSpelling(self.to_raw())) }
+    }
+    pub fn is_declaration(&self) -> bool {
+        unsafe { clang_sys::clang_isDeclaration(self.to_raw()) != 0 }
+    }
+    pub fn is_reference(&self) -> bool {
+        unsafe { clang_sys::clang_isReference(self.to_raw()) != 0 }
+    }
+    pub fn is_expression(&self) -> bool {
+        unsafe { clang_sys::clang_isExpression(self.to_raw()) != 0 }
+    }
+    pub fn is_statement(&self) -> bool {
+        unsafe { clang_sys::clang_isStatement(self.to_raw()) != 0 }
+    }
+    pub fn is_attribute(&self) -> bool {
+        unsafe { clang_sys::clang_isAttribute(self.to_raw()) != 0 }
+    }
+    pub fn is_invalid(&self) -> bool {
+        unsafe { clang_sys::clang_isInvalid(self.to_raw()) != 0 }
+    }
+    pub fn is_translation_unit(&self) -> bool {
+        unsafe { clang_sys::clang_isTranslationUnit(self.to_raw()) != 0 }
+    }
+    pub fn is_preprocessing(&self) -> bool {
+        unsafe { clang_sys::clang_isPreprocessing(self.to_raw()) != 0 }
+    }
+    pub fn is_unexposed(&self) -> bool {
+        unsafe { clang_sys::clang_isUnexposed(self.to_raw()) != 0 }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_small_value_enum(&CursorKind::FunctionDecl);
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        for kind in [
+            CursorKind::UnexposedDecl,
+            CursorKind::FunctionTemplate,
+            CursorKind::TranslationUnit,
+            CursorKind::OverloadCandidate,
+            CursorKind::Unknown(100_000),
+        ] {
+            assert_eq!(CursorKind::from_raw(kind.to_raw()), kind);
+        }
+        assert_eq!(CursorKind::from_raw(100_000), CursorKind::Unknown(100_000));
+        assert_eq!(
+            CursorKind::StructDecl.to_raw(),
+            clang_sys::CXCursor_StructDecl
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::marker::PhantomData;
+
+use super::{SourceLocation, SourceRange, TranslationUnit};
+use crate::utility::{cxstring_into_string, define_flags};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticSeverity {
+    Ignored,
+    Note,
+    Warning,
+    Error,
+    Fatal,
+}
+
+impl DiagnosticSeverity {
+    fn from_raw(raw: clang_sys::CXDiagnosticSeverity) -> Self {
+        match raw {
+            clang_sys::CXDiagnostic_Ignored => Self::Ignored,
+            clang_sys::CXDiagnostic_Note => Self::Note,
+            clang_sys::CXDiagnostic_Warning => Self::Warning,
+            clang_sys::CXDiagnostic_Error => Self::Error,
+            clang_sys::CXDiagnostic_Fatal => Self::Fatal,
+            s => unreachable!("unexpected CXDiagnosticSeverity {}", s),
+        }
+    }
+}
+
+define_flags! {
+    /// what [`Diagnostic::format`] includes besides the message, mirrors `CXDiagnosticDisplayOptions`
+    pub struct DiagnosticDisplayOptions: clang_sys::CXDiagnosticDisplayOptions {
+        const SOURCE_LOCATION = clang_sys::CXDiagnostic_DisplaySourceLocation;
+        const COLUMN = clang_sys::CXDiagnostic_DisplayColumn;
+        const SOURCE_RANGES = clang_sys::CXDiagnostic_DisplaySourceRanges;
+        const OPTION = clang_sys::CXDiagnostic_DisplayOption;
+        const CATEGORY_ID = clang_sys::CXDiagnostic_DisplayCategoryId;
+        const CATEGORY_NAME = clang_sys::CXDiagnostic_DisplayCategoryName;
+    }
+}
+
+impl DiagnosticDisplayOptions {
+    /// the options clang itself uses, mimicking the command line compiler
+    pub fn clang_default() -> Self {
+        Self::from_raw(unsafe { clang_sys::clang_defaultDiagnosticDisplayOptions() })
+    }
+}
+
+/// the warning option that controls a diagnostic
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DiagnosticOption {
+    /// the option enabling the diagnostic, e.g. `-Wconversion`
+    pub enable: String,
+    /// the option disabling the diagnostic, e.g. `-Wno-conversion`
+    pub disable: String,
+}
+
+#[derive(Debug)]
+pub struct FixIt<'diagnostic> {
+    /// text to replace `range` with, empty if it is a removal
+    pub replacement: String,
+    /// range to be replaced, it is an insertion if start equals end
+    pub range: SourceRange<'diagnostic>,
+}
+
+#[derive(Debug)]
+pub struct Diagnostic<'tu> {
+    raw: clang_sys::CXDiagnostic,
+    tu: clang_sys::CXTranslationUnit,
+    _tu: PhantomData<&'tu TranslationUnit<'tu>>,
+}
+
+impl<'tu> Drop for Diagnostic<'tu> {
+    fn drop(&mut self) {
+        unsafe { clang_sys::clang_disposeDiagnostic(self.raw) };
+    }
+}
+
+impl<'tu> Diagnostic<'tu> {
+    pub(crate) fn from_raw(raw: clang_sys::CXDiagnostic, tu: clang_sys::CXTranslationUnit) -> Self {
+        assert!(!raw.is_null());
+        Self {
+            raw,
+            tu,
+            _tu: PhantomData,
+        }
+    }
+
+    pub fn severity(&self) -> DiagnosticSeverity {
+        DiagnosticSeverity::from_raw(unsafe { clang_sys::clang_getDiagnosticSeverity(self.raw) })
+    }
+    pub fn message(&self) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_getDiagnosticSpelling(self.raw)) }
+    }
+    /// category number, `0` means no category
+    pub fn category(&self) -> u32 {
+        unsafe { clang_sys::clang_getDiagnosticCategory(self.raw) }
+    }
+    pub fn category_text(&self) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_getDiagnosticCategoryText(self.raw)) }
+    }
+    /// `None` if the diagnostic cannot be controlled by a command line option
+    pub fn option(&self) -> Option<DiagnosticOption> {
+        let mut disable = clang_sys::CXString::default();
+        let enable = unsafe {
+            let enable = clang_sys::clang_getDiagnosticOption(self.raw, &mut disable);
+            DiagnosticOption {
+                enable: cxstring_into_string(enable),
+                disable: cxstring_into_string(disable),
+            }
+        };
+        (!enable.enable.is_empty()).then_some(enable)
+    }
+    pub fn location(&self) -> SourceLocation<'_> {
+        SourceLocation::from_raw(
+            unsafe { clang_sys::clang_getDiagnosticLocation(self.raw) },
+            self.tu,
+        )
+    }
+    pub fn ranges(&self) -> Vec<SourceRange<'_>> {
+        let n = unsafe { clang_sys::clang_getDiagnosticNumRanges(self.raw) };
+        (0..n)
+            .map(|i| {
+                SourceRange::from_raw(
+                    unsafe { clang_sys::clang_getDiagnosticRange(self.raw, i) },
+                    self.tu,
+                )
+            })
+            .collect()
+    }
+    pub fn fix_its(&self) -> Vec<FixIt<'_>> {
+        let n = unsafe { clang_sys::clang_getDiagnosticNumFixIts(self.raw) };
+        (0..n)
+            .map(|i| {
+                let mut range = clang_sys::CXSourceRange::default();
+                let replacement = unsafe {
+                    cxstring_into_string(clang_sys::clang_getDiagnosticFixIt(
+                        self.raw, i, &mut range,
+                    ))
+                };
+                FixIt {
+                    replacement,
+                    range: SourceRange::from_raw(range, self.tu),
+                }
+            })
+            .collect()
+    }
+    /// notes attached to this diagnostic
+    pub fn children(&self) -> Diagnostics<'_> {
+        // the set is owned by this diagnostic, no need to dispose it
+        let set = unsafe { clang_sys::clang_getChildDiagnostics(self.raw) };
+        Diagnostics::from_set(set, self.tu)
+    }
+    pub fn format(&self, options: DiagnosticDisplayOptions) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_formatDiagnostic(self.raw, options.bits())) }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DiagnosticSource {
+    TranslationUnit(clang_sys::CXTranslationUnit),
+    Set(clang_sys::CXDiagnosticSet),
+    CodeCompletion(*mut clang_sys::CXCodeCompleteResults),
+}
+
+/// iterator over [`Diagnostic`]s of a translation unit, code completion results
+/// or child notes of a diagnostic
+#[derive(Debug)]
+pub struct Diagnostics<'tu> {
+    source: DiagnosticSource,
+    tu: clang_sys::CXTranslationUnit,
+    index: u32,
+    len: u32,
+    _tu: PhantomData<&'tu TranslationUnit<'tu>>,
+}
+
+impl<'tu> Diagnostics<'tu> {
+    pub(crate) fn from_translation_unit(tu: clang_sys::CXTranslationUnit) -> Self {
+        Self {
+            source: DiagnosticSource::TranslationUnit(tu),
+            tu,
+            index: 0,
+            len: unsafe { clang_sys::clang_getNumDiagnostics(tu) },
+            _tu: PhantomData,
+        }
+    }
+    pub(crate) fn from_set(
+        set: clang_sys::CXDiagnosticSet,
+        tu: clang_sys::CXTranslationUnit,
+    ) -> Self {
+        let len = if set.is_null() {
+            0
+        } else {
+            unsafe { clang_sys::clang_getNumDiagnosticsInSet(set) }
+        };
+        Self {
+            source: DiagnosticSource::Set(set),
+            tu,
+            index: 0,
+            len,
+            _tu: PhantomData,
+        }
+    }
+    pub(crate) fn from_code_complete_results(
+        results: *mut clang_sys::CXCodeCompleteResults,
+        tu: clang_sys::CXTranslationUnit,
+    ) -> Self {
+        Self {
+            source: DiagnosticSource::CodeCompletion(results),
+            tu,
+            index: 0,
+            len: unsafe { clang_sys::clang_codeCompleteGetNumDiagnostics(results) },
+            _tu: PhantomData,
+        }
+    }
+}
+
+impl<'tu> Iterator for Diagnostics<'tu> {
+    type Item = Diagnostic<'tu>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let raw = unsafe {
+            match self.source {
+                DiagnosticSource::TranslationUnit(tu) => {
+                    clang_sys::clang_getDiagnostic(tu, self.index)
+                }
+                DiagnosticSource::Set(set) => clang_sys::clang_getDiagnosticInSet(set, self.index),
+                DiagnosticSource::CodeCompletion(results) => {
+                    clang_sys::clang_codeCompleteGetDiagnostic(results, self.index)
+                }
+            }
+        };
+        self.index += 1;
+        Some(Diagnostic::from_raw(raw, self.tu))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'tu> ExactSizeIterator for Diagnostics<'tu> {}
+
+impl<'index> TranslationUnit<'index> {
+    pub fn diagnostics(&self) -> Diagnostics<'_> {
+        Diagnostics::from_translation_unit(self.raw)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_small_value_enum(&DiagnosticSeverity::Warning);
+        is_small_value_struct(&DiagnosticDisplayOptions::COLUMN);
+
+        let diagnostic = Diagnostic {
+            raw: std::ptr::null_mut(),
+            tu: std::ptr::null_mut(),
+            _tu: PhantomData,
+        };
+        is_ffi_struct(&diagnostic);
+        std::mem::forget(diagnostic);
+
+        let diagnostics = Diagnostics::from_set(std::ptr::null_mut(), std::ptr::null_mut());
+        is_ffi_struct(&diagnostics);
+        assert_eq!(diagnostics.len(), 0);
+    }
+
+    #[test]
+    fn display_options() {
+        let mut options =
+            DiagnosticDisplayOptions::SOURCE_LOCATION | DiagnosticDisplayOptions::COLUMN;
+        assert!(options.contains(DiagnosticDisplayOptions::COLUMN));
+        assert!(!options.contains(DiagnosticDisplayOptions::OPTION));
+        options.remove(DiagnosticDisplayOptions::COLUMN);
+        assert_eq!(options, DiagnosticDisplayOptions::SOURCE_LOCATION);
+        assert!(DiagnosticDisplayOptions::NONE.is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+use std::ffi::CStr;
+
+use super::{Cursor, CursorKind};
+
+/// an integer value together with its signedness
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IntValue {
+    Signed(i64),
+    Unsigned(u64),
+}
+
+impl IntValue {
+    /// `None` if the value does not fit
+    pub fn as_i64(self) -> Option<i64> {
+        match self {
+            Self::Signed(value) => Some(value),
+            Self::Unsigned(value) => i64::try_from(value).ok(),
+        }
+    }
+    /// `None` if the value does not fit
+    pub fn as_u64(self) -> Option<u64> {
+        match self {
+            Self::Signed(value) => u64::try_from(value).ok(),
+            Self::Unsigned(value) => Some(value),
+        }
+    }
+}
+
+/// the value of a constant expression, see [`Cursor::evaluate`]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum EvalResult {
+    Int(IntValue),
+    Float(f64),
+    /// a string literal, e.g. `"abc"`
+    StrLiteral(String),
+    /// an Objective-C string literal, e.g. `@"abc"`
+    ObjCStrLiteral(String),
+    /// a `CFSTR("abc")` constant
+    CFStr(String),
+    /// a constant of another kind, e.g. a pointer or a struct, whose value is not exposed
+    Other,
+}
+
+/// disposes the result even if converting it panics
+struct RawEvalResult(clang_sys::CXEvalResult);
+
+impl Drop for RawEvalResult {
+    fn drop(&mut self) {
+        unsafe { clang_sys::clang_EvalResult_dispose(self.0) };
+    }
+}
+
+impl RawEvalResult {
+    fn string(&self) -> String {
+        let s = unsafe { clang_sys::clang_EvalResult_getAsStr(self.0) };
+        if s.is_null() {
+            return String::new();
+        }
+        unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned()
+    }
+
+    fn to_result(&self) -> Option<EvalResult> {
+        let raw = self.0;
+        let result = match unsafe { clang_sys::clang_EvalResult_getKind(raw) } {
+            clang_sys::CXEval_UnExposed => return None,
+            clang_sys::CXEval_Int => EvalResult::Int(
+                if unsafe { clang_sys::clang_EvalResult_isUnsignedInt(raw) } != 0 {
+                    IntValue::Unsigned(unsafe { clang_sys::clang_EvalResult_getAsUnsigned(raw) })
+                } else {
+                    IntValue::Signed(unsafe { clang_sys::clang_EvalResult_getAsLongLong(raw) })
+                },
+            ),
+            clang_sys::CXEval_Float => {
+                EvalResult::Float(unsafe { clang_sys::clang_EvalResult_getAsDouble(raw) })
+            }
+            clang_sys::CXEval_StrLiteral => EvalResult::StrLiteral(self.string()),
+            clang_sys::CXEval_ObjCStrLiteral => EvalResult::ObjCStrLiteral(self.string()),
+            clang_sys::CXEval_CFStr => EvalResult::CFStr(self.string()),
+            clang_sys::CXEval_Other => EvalResult::Other,
+            k => unreachable!("unexpected CXEvalResultKind {}", k),
+        };
+        Some(result)
+    }
+}
+
+impl<'tu> Cursor<'tu> {
+    /// evaluate an expression, or the initializer of a variable, at compile time
+    ///
+    /// `None` if it is not a constant expression, and for [`CursorKind::MacroDefinition`]s,
+    /// whose value is only known where they are expanded, e.g. in the initializer of a
+    /// `constexpr` variable
+    pub fn evaluate(&self) -> Option<EvalResult> {
+        let raw = unsafe { clang_sys::clang_Cursor_Evaluate(self.raw) };
+        if raw.is_null() {
+            return None;
+        }
+        RawEvalResult(raw).to_result()
+    }
+    /// `None` if the cursor is not an enumerator
+    pub fn enum_constant_value(&self) -> Option<i64> {
+        (self.kind() == CursorKind::EnumConstantDecl)
+            .then(|| unsafe { clang_sys::clang_getEnumConstantDeclValue(self.raw) })
+    }
+    /// `None` if the cursor is not an enumerator
+    pub fn enum_constant_unsigned_value(&self) -> Option<u64> {
+        (self.kind() == CursorKind::EnumConstantDecl)
+            .then(|| unsafe { clang_sys::clang_getEnumConstantDeclUnsignedValue(self.raw) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_small_value_enum(&IntValue::Signed(-1));
+        is_partial_eq_value_enum(&EvalResult::Other);
+    }
+
+    #[test]
+    fn int_value() {
+        assert_eq!(IntValue::Signed(-1).as_i64(), Some(-1));
+        assert_eq!(IntValue::Signed(-1).as_u64(), None);
+        assert_eq!(IntValue::Unsigned(u64::MAX).as_i64(), None);
+        assert_eq!(IntValue::Unsigned(42).as_i64(), Some(42));
+        assert_eq!(IntValue::Unsigned(42).as_u64(), Some(42));
+    }
+}
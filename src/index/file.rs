@@ -0,0 +1,121 @@
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use super::TranslationUnit;
+use crate::utility::{cxstring_into_string, path_to_cstring};
+
+/// uniquely identifies a file across translation units, see [`File::unique_id`]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FileUniqueId([u64; 3]);
+
+/// a file within a translation unit, compared and hashed by its [`FileUniqueId`]
+#[derive(Clone, Copy)]
+pub struct File<'tu> {
+    raw: clang_sys::CXFile,
+    tu: clang_sys::CXTranslationUnit,
+    _tu: PhantomData<&'tu TranslationUnit<'tu>>,
+}
+
+impl<'tu> File<'tu> {
+    /// `None` if `raw` is null
+    pub(crate) fn from_raw(
+        raw: clang_sys::CXFile,
+        tu: clang_sys::CXTranslationUnit,
+    ) -> Option<Self> {
+        (!raw.is_null()).then_some(Self {
+            raw,
+            tu,
+            _tu: PhantomData,
+        })
+    }
+
+    pub(crate) fn raw(&self) -> clang_sys::CXFile {
+        self.raw
+    }
+
+    /// the name as it was passed to the compiler
+    pub fn name(&self) -> PathBuf {
+        PathBuf::from(unsafe { cxstring_into_string(clang_sys::clang_getFileName(self.raw)) })
+    }
+    /// the path with symlinks and `..` resolved, `None` if it is unknown
+    pub fn real_path(&self) -> Option<PathBuf> {
+        let path =
+            unsafe { cxstring_into_string(clang_sys::clang_File_tryGetRealPathName(self.raw)) };
+        (!path.is_empty()).then(|| PathBuf::from(path))
+    }
+    /// last modification time
+    pub fn mtime(&self) -> SystemTime {
+        let secs = unsafe { clang_sys::clang_getFileTime(self.raw) };
+        let offset = Duration::from_secs(secs.unsigned_abs() as u64);
+        if secs >= 0 {
+            SystemTime::UNIX_EPOCH + offset
+        } else {
+            SystemTime::UNIX_EPOCH - offset
+        }
+    }
+    /// `None` if the file cannot be identified, e.g. it only exists in memory
+    pub fn unique_id(&self) -> Option<FileUniqueId> {
+        let mut id = clang_sys::CXFileUniqueID::default();
+        let error = unsafe { clang_sys::clang_getFileUniqueID(self.raw, &mut id) };
+        (error == 0).then_some(FileUniqueId(id.data))
+    }
+    /// contents as seen by the translation unit, including unsaved files,
+    /// `None` if the file is not part of it
+    pub fn contents(&self) -> Option<&'tu [u8]> {
+        let mut size = 0;
+        let ptr = unsafe { clang_sys::clang_getFileContents(self.tu, self.raw, &mut size) };
+        (!ptr.is_null()).then(|| unsafe { std::slice::from_raw_parts(ptr as *const u8, size) })
+    }
+    /// guarded against multiple inclusion, by `#pragma once` or an include guard
+    pub fn is_multiple_include_guarded(&self) -> bool {
+        unsafe { clang_sys::clang_isFileMultipleIncludeGuarded(self.tu, self.raw) != 0 }
+    }
+}
+
+impl<'tu> std::fmt::Debug for File<'tu> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("File").field(&self.name()).finish()
+    }
+}
+
+impl<'tu> PartialEq for File<'tu> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.unique_id(), other.unique_id()) {
+            (Some(lhs), Some(rhs)) => lhs == rhs,
+            _ => self.raw == other.raw,
+        }
+    }
+}
+
+impl<'tu> Eq for File<'tu> {}
+
+impl<'tu> std::hash::Hash for File<'tu> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.unique_id().hash(state);
+    }
+}
+
+impl<'index> TranslationUnit<'index> {
+    /// `None` if `path` is not part of this translation unit
+    pub fn file<P: AsRef<Path>>(&self, path: P) -> Option<File<'_>> {
+        let filename = path_to_cstring(path).ok()?;
+        File::from_raw(
+            unsafe { clang_sys::clang_getFile(self.raw, filename.as_ptr()) },
+            self.raw,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_small_value_struct(&FileUniqueId::default());
+        assert!(File::from_raw(std::ptr::null_mut(), std::ptr::null_mut()).is_none());
+    }
+}
@@ -0,0 +1,127 @@
+use super::{Cursor, File, SourceRange, TranslationUnit};
+use crate::utility::CaughtPanic;
+
+/// what to do after a reference or an include is found, or after an
+/// [`IndexerCallbacks`](super::IndexerCallbacks) method, mirrors `CXVisitorResult`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Visit {
+    Break,
+    Continue,
+}
+
+impl Visit {
+    fn to_raw(self) -> clang_sys::CXVisitorResult {
+        match self {
+            Self::Break => clang_sys::CXVisit_Break,
+            Self::Continue => clang_sys::CXVisit_Continue,
+        }
+    }
+}
+
+/// how a search ended, mirrors `CXResult`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FindResult {
+    /// everything has been visited
+    Completed,
+    /// the cursor or the file cannot be searched
+    Invalid,
+    /// the visitor returned [`Visit::Break`]
+    Stopped,
+}
+
+impl FindResult {
+    fn from_raw(raw: clang_sys::CXResult) -> Self {
+        match raw {
+            clang_sys::CXResult_Success => Self::Completed,
+            clang_sys::CXResult_Invalid => Self::Invalid,
+            clang_sys::CXResult_VisitBreak => Self::Stopped,
+            r => unreachable!("unexpected CXResult {}", r),
+        }
+    }
+}
+
+/// call `find` with a visitor calling `f`, a panic inside `f` stops the search and is resumed
+fn find_with<'tu, F, G>(tu: clang_sys::CXTranslationUnit, f: F, find: G) -> FindResult
+where
+    F: FnMut(Cursor<'tu>, SourceRange<'tu>) -> Visit,
+    G: FnOnce(clang_sys::CXCursorAndRangeVisitor) -> clang_sys::CXResult,
+{
+    struct State<F> {
+        f: F,
+        tu: clang_sys::CXTranslationUnit,
+        panic: CaughtPanic,
+    }
+    extern "C" fn visitor<'tu, F>(
+        context: *mut std::os::raw::c_void,
+        cursor: clang_sys::CXCursor,
+        range: clang_sys::CXSourceRange,
+    ) -> clang_sys::CXVisitorResult
+    where
+        F: FnMut(Cursor<'tu>, SourceRange<'tu>) -> Visit,
+    {
+        let state = unsafe { &mut *(context as *mut State<F>) };
+        let (f, tu) = (&mut state.f, state.tu);
+        state
+            .panic
+            .catch(|| f(Cursor::from_raw(cursor), SourceRange::from_raw(range, tu)))
+            .map_or(clang_sys::CXVisit_Break, Visit::to_raw)
+    }
+    let mut state = State {
+        f,
+        tu,
+        panic: CaughtPanic::default(),
+    };
+    let result = find(clang_sys::CXCursorAndRangeVisitor {
+        context: &mut state as *mut State<F> as *mut std::os::raw::c_void,
+        visit: Some(visitor::<F>),
+    });
+    state.panic.resume();
+    FindResult::from_raw(result)
+}
+
+impl<'tu> Cursor<'tu> {
+    /// call `f` with each reference to the entity of this cursor in `file`,
+    /// along with the range of the reference
+    pub fn find_references_in_file<F>(&self, file: &File<'tu>, f: F) -> FindResult
+    where
+        F: FnMut(Cursor<'tu>, SourceRange<'tu>) -> Visit,
+    {
+        find_with(self.tu(), f, |visitor| unsafe {
+            clang_sys::clang_findReferencesInFile(self.raw, file.raw(), visitor)
+        })
+    }
+}
+
+impl<'index> TranslationUnit<'index> {
+    /// call `f` with each `#include` directive in `file`, along with its range
+    pub fn find_includes_in_file<'tu, F>(&'tu self, file: &File<'tu>, f: F) -> FindResult
+    where
+        F: FnMut(Cursor<'tu>, SourceRange<'tu>) -> Visit,
+    {
+        find_with(self.raw, f, |visitor| unsafe {
+            clang_sys::clang_findIncludesInFile(self.raw, file.raw(), visitor)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_small_value_enum(&Visit::Continue);
+        is_small_value_enum(&FindResult::Completed);
+    }
+
+    #[test]
+    fn raw_values() {
+        assert_eq!(Visit::Break.to_raw(), clang_sys::CXVisit_Break);
+        assert_eq!(
+            FindResult::from_raw(clang_sys::CXResult_VisitBreak),
+            FindResult::Stopped
+        );
+    }
+}
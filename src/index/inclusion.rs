@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use super::{Cursor, File, SourceLocation, TranslationUnit, Visit};
+
+/// a file of a translation unit and how it is included, see [`TranslationUnit::inclusions`]
+#[derive(Debug, Clone)]
+pub struct Inclusion<'tu> {
+    pub file: File<'tu>,
+    /// locations of the `#include` directives, from the one including `file` up to the main file,
+    /// empty for the main file
+    pub stack: Vec<SourceLocation<'tu>>,
+}
+
+impl<'index> TranslationUnit<'index> {
+    /// all files of this translation unit, the main file comes first
+    pub fn inclusions(&self) -> Vec<Inclusion<'_>> {
+        struct State<'tu> {
+            tu: clang_sys::CXTranslationUnit,
+            inclusions: Vec<Inclusion<'tu>>,
+        }
+        extern "C" fn visitor(
+            file: clang_sys::CXFile,
+            stack: *mut clang_sys::CXSourceLocation,
+            len: std::os::raw::c_uint,
+            data: clang_sys::CXClientData,
+        ) {
+            let state = unsafe { &mut *(data as *mut State) };
+            let Some(file) = File::from_raw(file, state.tu) else {
+                return;
+            };
+            let stack = if stack.is_null() {
+                &[]
+            } else {
+                unsafe { std::slice::from_raw_parts(stack, len as usize) }
+            };
+            let stack = stack
+                .iter()
+                .map(|&location| SourceLocation::from_raw(location, state.tu))
+                .collect();
+            state.inclusions.push(Inclusion { file, stack });
+        }
+        let mut state = State {
+            tu: self.raw,
+            inclusions: Vec::new(),
+        };
+        unsafe {
+            clang_sys::clang_getInclusions(
+                self.raw,
+                visitor,
+                &mut state as *mut State as clang_sys::CXClientData,
+            )
+        };
+        state.inclusions
+    }
+    /// the graph of [`TranslationUnit::inclusions`], completed with the `#include` directives
+    /// of each file
+    ///
+    /// the directives are only known when parsed with
+    /// [`ParseOptions::DETAILED_PREPROCESSING_RECORD`](super::ParseOptions::DETAILED_PREPROCESSING_RECORD),
+    /// otherwise the graph is the same as [`IncludeGraph::from_inclusions`]
+    pub fn include_graph(&self) -> IncludeGraph {
+        let inclusions = self.inclusions();
+        let mut graph = IncludeGraph::from_inclusions(&inclusions);
+        for inclusion in &inclusions {
+            self.find_includes_in_file(&inclusion.file, |directive, _| {
+                if let Some(file) = directive.included_file() {
+                    graph.insert_include(inclusion.file.name(), file.name());
+                }
+                Visit::Continue
+            });
+        }
+        graph
+    }
+}
+
+impl<'tu> Cursor<'tu> {
+    /// the file an `#include` directive includes, `None` for other cursors
+    pub fn included_file(&self) -> Option<File<'tu>> {
+        File::from_raw(
+            unsafe { clang_sys::clang_getIncludedFile(self.raw) },
+            self.tu(),
+        )
+    }
+}
+
+/// directed graph of files including other files, see [`TranslationUnit::include_graph`]
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IncludeGraph {
+    /// files with the shortest include depth they are reached at, `0` for the main file
+    depths: BTreeMap<PathBuf, usize>,
+    /// files included directly by a file
+    includes: BTreeMap<PathBuf, BTreeSet<PathBuf>>,
+}
+
+#[derive(Serialize)]
+struct JsonIncludeNode<'graph> {
+    path: &'graph Path,
+    depth: usize,
+    includes: Vec<&'graph Path>,
+}
+
+impl IncludeGraph {
+    /// only inclusions which entered a file are known, so a guarded header included
+    /// a second time has no edge from its second includer
+    pub fn from_inclusions(inclusions: &[Inclusion]) -> Self {
+        let mut graph = Self::default();
+        for inclusion in inclusions {
+            let file = inclusion.file.name();
+            let depth = inclusion.stack.len();
+            graph
+                .depths
+                .entry(file.clone())
+                .and_modify(|d| *d = depth.min(*d))
+                .or_insert(depth);
+            let includer = inclusion
+                .stack
+                .first()
+                .and_then(|location| location.file_location().file);
+            if let Some(includer) = includer {
+                graph.insert_include(includer.name(), file);
+            }
+        }
+        graph
+    }
+    fn insert_include(&mut self, includer: PathBuf, file: PathBuf) {
+        self.includes.entry(includer).or_default().insert(file);
+    }
+
+    /// all files in path order
+    pub fn files(&self) -> impl Iterator<Item = &Path> + '_ {
+        self.depths.keys().map(PathBuf::as_path)
+    }
+    /// `None` if `file` is not in the graph
+    pub fn depth<P: AsRef<Path>>(&self, file: P) -> Option<usize> {
+        self.depths.get(file.as_ref()).copied()
+    }
+    /// files `file` includes directly
+    pub fn includes<P: AsRef<Path>>(&self, file: P) -> impl Iterator<Item = &Path> + '_ {
+        self.includes
+            .get(file.as_ref())
+            .into_iter()
+            .flatten()
+            .map(PathBuf::as_path)
+    }
+
+    /// Graphviz DOT format, each file is a node named by its path with a `depth` attribute
+    pub fn to_dot(&self) -> String {
+        let quote = |path: &Path| format!("{:?}", path.to_string_lossy());
+        let mut dot = String::from("digraph includes {\n");
+        for (file, depth) in &self.depths {
+            dot += &format!("    {} [depth={}];\n", quote(file), depth);
+        }
+        for (includer, included) in &self.includes {
+            for file in included {
+                dot += &format!("    {} -> {};\n", quote(includer), quote(file));
+            }
+        }
+        dot += "}\n";
+        dot
+    }
+    /// a JSON array of `{"path", "depth", "includes"}` objects
+    pub fn to_json(&self) -> String {
+        let nodes = self
+            .depths
+            .iter()
+            .map(|(path, &depth)| JsonIncludeNode {
+                path,
+                depth,
+                includes: self.includes(path).collect(),
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_string_pretty(&nodes)
+            .unwrap_or_else(|e| unreachable!("include graph is always serializable, {}", e))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn graph() -> IncludeGraph {
+        let mut graph = IncludeGraph::default();
+        for (file, depth) in [("main.cpp", 0), ("a.h", 1), ("b \"quoted\".h", 2)] {
+            graph.depths.insert(PathBuf::from(file), depth);
+        }
+        graph
+            .includes
+            .entry(PathBuf::from("main.cpp"))
+            .or_default()
+            .insert(PathBuf::from("a.h"));
+        graph
+            .includes
+            .entry(PathBuf::from("a.h"))
+            .or_default()
+            .insert(PathBuf::from("b \"quoted\".h"));
+        graph
+    }
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_ffi_struct(&IncludeGraph::default());
+        assert!(IncludeGraph::from_inclusions(&[]).files().next().is_none());
+    }
+
+    #[test]
+    fn queries() {
+        let graph = graph();
+        assert_eq!(
+            graph.files().collect::<Vec<_>>(),
+            ["a.h", "b \"quoted\".h", "main.cpp"]
+        );
+        assert_eq!(graph.depth("a.h"), Some(1));
+        assert_eq!(graph.depth("c.h"), None);
+        assert_eq!(graph.includes("main.cpp").collect::<Vec<_>>(), ["a.h"]);
+        assert_eq!(graph.includes("c.h").count(), 0);
+    }
+
+    #[test]
+    fn export() {
+        let graph = graph();
+        assert_eq!(
+            graph.to_dot(),
+            r#"digraph includes {
+    "a.h" [depth=1];
+    "b \"quoted\".h" [depth=2];
+    "main.cpp" [depth=0];
+    "a.h" -> "b \"quoted\".h";
+    "main.cpp" -> "a.h";
+}
+"#
+        );
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"path": "a.h", "depth": 1, "includes": ["b \"quoted\".h"]},
+                {"path": "b \"quoted\".h", "depth": 2, "includes": []},
+                {"path": "main.cpp", "depth": 0, "includes": ["a.h"]},
+            ])
+        );
+    }
+}
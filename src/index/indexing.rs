@@ -0,0 +1,736 @@
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::Path;
+
+use super::{
+    Cursor, Diagnostics, File, Index, ParseError, ParseOptions, RawUnsavedFiles, SourceLocation,
+    TranslationUnit, UnsavedFile, Visit,
+};
+use crate::error::{Error, Result};
+use crate::utility::{define_flags, path_to_cstring, CStringArray, CaughtPanic};
+
+define_flags! {
+    /// what an [`IndexAction`] reports, mirrors `CXIndexOptFlags`
+    pub struct IndexOptions: clang_sys::CXIndexOptFlags {
+        /// report only the first reference to an entity within a declaration, e.g. `Foo` in `void f(Foo, Foo)`
+        const SUPPRESS_REDUNDANT_REFS = clang_sys::CXIndexOptSuppressRedundantRefs;
+        /// report local variables and other function-local symbols
+        const INDEX_FUNCTION_LOCAL_SYMBOLS = clang_sys::CXIndexOptIndexFunctionLocalSymbols;
+        /// report implicit template instantiations
+        const INDEX_IMPLICIT_TEMPLATE_INSTANTIATIONS = clang_sys::CXIndexOptIndexImplicitTemplateInstantiations;
+        /// drop all compiler warnings
+        const SUPPRESS_WARNINGS = clang_sys::CXIndexOptSuppressWarnings;
+        /// skip function bodies already indexed by the same [`IndexAction`], e.g. in shared headers
+        const SKIP_PARSED_BODIES_IN_SESSION = clang_sys::CXIndexOptSkipParsedBodiesInSession;
+    }
+}
+
+define_flags! {
+    /// how an entity is used by a reference, mirrors `CXSymbolRole`
+    pub struct SymbolRoles: clang_sys::CXSymbolRole {
+        const DECLARATION = clang_sys::CXSymbolRole_Declaration;
+        const DEFINITION = clang_sys::CXSymbolRole_Definition;
+        const REFERENCE = clang_sys::CXSymbolRole_Reference;
+        const READ = clang_sys::CXSymbolRole_Read;
+        const WRITE = clang_sys::CXSymbolRole_Write;
+        const CALL = clang_sys::CXSymbolRole_Call;
+        /// a call which may be dispatched dynamically, e.g. of a virtual method
+        const DYNAMIC = clang_sys::CXSymbolRole_Dynamic;
+        const ADDRESS_OF = clang_sys::CXSymbolRole_AddressOf;
+        const IMPLICIT = clang_sys::CXSymbolRole_Implicit;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum EntityKind {
+    Unexposed,
+    Typedef,
+    Function,
+    Variable,
+    Field,
+    EnumConstant,
+    ObjCClass,
+    ObjCProtocol,
+    ObjCCategory,
+    ObjCInstanceMethod,
+    ObjCClassMethod,
+    ObjCProperty,
+    ObjCIvar,
+    Enum,
+    Struct,
+    Union,
+    CXXClass,
+    CXXNamespace,
+    CXXNamespaceAlias,
+    CXXStaticVariable,
+    CXXStaticMethod,
+    CXXInstanceMethod,
+    CXXConstructor,
+    CXXDestructor,
+    CXXConversionFunction,
+    CXXTypeAlias,
+    CXXInterface,
+    CXXConcept,
+    /// a kind added to libclang after this crate, with its raw `CXIdxEntityKind` value
+    Unknown(i32),
+}
+
+impl EntityKind {
+    fn from_raw(raw: clang_sys::CXIdxEntityKind) -> Self {
+        match raw {
+            clang_sys::CXIdxEntity_Unexposed => Self::Unexposed,
+            clang_sys::CXIdxEntity_Typedef => Self::Typedef,
+            clang_sys::CXIdxEntity_Function => Self::Function,
+            clang_sys::CXIdxEntity_Variable => Self::Variable,
+            clang_sys::CXIdxEntity_Field => Self::Field,
+            clang_sys::CXIdxEntity_EnumConstant => Self::EnumConstant,
+            clang_sys::CXIdxEntity_ObjCClass => Self::ObjCClass,
+            clang_sys::CXIdxEntity_ObjCProtocol => Self::ObjCProtocol,
+            clang_sys::CXIdxEntity_ObjCCategory => Self::ObjCCategory,
+            clang_sys::CXIdxEntity_ObjCInstanceMethod => Self::ObjCInstanceMethod,
+            clang_sys::CXIdxEntity_ObjCClassMethod => Self::ObjCClassMethod,
+            clang_sys::CXIdxEntity_ObjCProperty => Self::ObjCProperty,
+            clang_sys::CXIdxEntity_ObjCIvar => Self::ObjCIvar,
+            clang_sys::CXIdxEntity_Enum => Self::Enum,
+            clang_sys::CXIdxEntity_Struct => Self::Struct,
+            clang_sys::CXIdxEntity_Union => Self::Union,
+            clang_sys::CXIdxEntity_CXXClass => Self::CXXClass,
+            clang_sys::CXIdxEntity_CXXNamespace => Self::CXXNamespace,
+            clang_sys::CXIdxEntity_CXXNamespaceAlias => Self::CXXNamespaceAlias,
+            clang_sys::CXIdxEntity_CXXStaticVariable => Self::CXXStaticVariable,
+            clang_sys::CXIdxEntity_CXXStaticMethod => Self::CXXStaticMethod,
+            clang_sys::CXIdxEntity_CXXInstanceMethod => Self::CXXInstanceMethod,
+            clang_sys::CXIdxEntity_CXXConstructor => Self::CXXConstructor,
+            clang_sys::CXIdxEntity_CXXDestructor => Self::CXXDestructor,
+            clang_sys::CXIdxEntity_CXXConversionFunction => Self::CXXConversionFunction,
+            clang_sys::CXIdxEntity_CXXTypeAlias => Self::CXXTypeAlias,
+            clang_sys::CXIdxEntity_CXXInterface => Self::CXXInterface,
+            clang_sys::CXIdxEntity_CXXConcept => Self::CXXConcept,
+            k => Self::Unknown(k),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum EntityTemplateKind {
+    NonTemplate,
+    Template,
+    TemplatePartialSpecialization,
+    TemplateSpecialization,
+    /// a value added to libclang after this crate, with its raw `CXIdxEntityCXXTemplateKind` value
+    Unknown(i32),
+}
+
+impl EntityTemplateKind {
+    fn from_raw(raw: clang_sys::CXIdxEntityCXXTemplateKind) -> Self {
+        match raw {
+            clang_sys::CXIdxEntity_NonTemplate => Self::NonTemplate,
+            clang_sys::CXIdxEntity_Template => Self::Template,
+            clang_sys::CXIdxEntity_TemplatePartialSpecialization => {
+                Self::TemplatePartialSpecialization
+            }
+            clang_sys::CXIdxEntity_TemplateSpecialization => Self::TemplateSpecialization,
+            k => Self::Unknown(k),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum EntityLanguage {
+    None,
+    C,
+    ObjC,
+    CXX,
+    Swift,
+    /// a value added to libclang after this crate, with its raw `CXIdxEntityLanguage` value
+    Unknown(i32),
+}
+
+impl EntityLanguage {
+    fn from_raw(raw: clang_sys::CXIdxEntityLanguage) -> Self {
+        match raw {
+            clang_sys::CXIdxEntityLang_None => Self::None,
+            clang_sys::CXIdxEntityLang_C => Self::C,
+            clang_sys::CXIdxEntityLang_ObjC => Self::ObjC,
+            clang_sys::CXIdxEntityLang_CXX => Self::CXX,
+            clang_sys::CXIdxEntityLang_Swift => Self::Swift,
+            l => Self::Unknown(l),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum EntityRefKind {
+    /// the entity is referenced in the source, e.g. by its name
+    Direct,
+    /// the entity is referenced implicitly, e.g. a property through dot syntax in Objective-C
+    Implicit,
+    /// a value added to libclang after this crate, with its raw `CXIdxEntityRefKind` value
+    Unknown(i32),
+}
+
+impl EntityRefKind {
+    fn from_raw(raw: clang_sys::CXIdxEntityRefKind) -> Self {
+        match raw {
+            clang_sys::CXIdxEntityRef_Direct => Self::Direct,
+            clang_sys::CXIdxEntityRef_Implicit => Self::Implicit,
+            k => Self::Unknown(k),
+        }
+    }
+}
+
+/// `None` for a null pointer or an empty string
+unsafe fn c_str_to_string(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    let s = CStr::from_ptr(ptr).to_string_lossy();
+    (!s.is_empty()).then(|| s.into_owned())
+}
+
+fn idx_location<'tu>(
+    raw: clang_sys::CXIdxLoc,
+    tu: clang_sys::CXTranslationUnit,
+) -> SourceLocation<'tu> {
+    SourceLocation::from_raw(
+        unsafe { clang_sys::clang_indexLoc_getCXSourceLocation(raw) },
+        tu,
+    )
+}
+
+fn container<'tu>(raw: *const clang_sys::CXIdxContainerInfo) -> Option<Cursor<'tu>> {
+    unsafe { raw.as_ref() }.and_then(|container| Cursor::from_raw_valid(container.cursor))
+}
+
+/// an entity declared or referenced, e.g. a function or a class
+#[derive(Debug, Clone)]
+pub struct EntityInfo<'tu> {
+    pub kind: EntityKind,
+    pub template_kind: EntityTemplateKind,
+    pub language: EntityLanguage,
+    /// `None` for anonymous entities
+    pub name: Option<String>,
+    pub usr: Option<String>,
+    /// the canonical declaration of the entity
+    pub cursor: Cursor<'tu>,
+}
+
+impl<'tu> EntityInfo<'tu> {
+    fn from_raw(raw: *const clang_sys::CXIdxEntityInfo) -> Option<Self> {
+        let raw = unsafe { raw.as_ref() }?;
+        Some(Self {
+            kind: EntityKind::from_raw(raw.kind),
+            template_kind: EntityTemplateKind::from_raw(raw.templateKind),
+            language: EntityLanguage::from_raw(raw.lang),
+            name: unsafe { c_str_to_string(raw.name) },
+            usr: unsafe { c_str_to_string(raw.USR) },
+            cursor: Cursor::from_raw(raw.cursor),
+        })
+    }
+}
+
+/// a declaration found by an [`IndexAction`]
+#[derive(Debug, Clone)]
+pub struct DeclInfo<'tu> {
+    pub entity: EntityInfo<'tu>,
+    pub cursor: Cursor<'tu>,
+    pub location: SourceLocation<'tu>,
+    /// e.g. the class of an out-of-line method definition
+    pub semantic_container: Option<Cursor<'tu>>,
+    /// e.g. the namespace enclosing an out-of-line method definition
+    pub lexical_container: Option<Cursor<'tu>>,
+    pub is_redeclaration: bool,
+    pub is_definition: bool,
+    /// the declaration contains other declarations, e.g. a class or a namespace
+    pub is_container: bool,
+    pub is_implicit: bool,
+    /// the body was skipped, see [`IndexOptions::SKIP_PARSED_BODIES_IN_SESSION`]
+    pub is_skipped: bool,
+}
+
+impl<'tu> DeclInfo<'tu> {
+    fn from_raw(raw: &clang_sys::CXIdxDeclInfo, tu: clang_sys::CXTranslationUnit) -> Self {
+        Self {
+            entity: EntityInfo::from_raw(raw.entityInfo)
+                .unwrap_or_else(|| unreachable!("declaration without entity")),
+            cursor: Cursor::from_raw(raw.cursor),
+            location: idx_location(raw.loc, tu),
+            semantic_container: container(raw.semanticContainer),
+            lexical_container: container(raw.lexicalContainer),
+            is_redeclaration: raw.isRedeclaration != 0,
+            is_definition: raw.isDefinition != 0,
+            is_container: raw.isContainer != 0,
+            is_implicit: raw.isImplicit != 0,
+            is_skipped: raw.flags & clang_sys::CXIdxDeclFlag_Skipped as u32 != 0,
+        }
+    }
+}
+
+/// a reference to an entity found by an [`IndexAction`]
+#[derive(Debug, Clone)]
+pub struct EntityRefInfo<'tu> {
+    pub kind: EntityRefKind,
+    /// the referencing expression or type reference
+    pub cursor: Cursor<'tu>,
+    pub location: SourceLocation<'tu>,
+    pub referenced: EntityInfo<'tu>,
+    /// the entity whose declaration contains the reference, e.g. the calling function,
+    /// `None` for references at global scope
+    pub parent: Option<EntityInfo<'tu>>,
+    pub container: Option<Cursor<'tu>>,
+    pub roles: SymbolRoles,
+}
+
+impl<'tu> EntityRefInfo<'tu> {
+    fn from_raw(raw: &clang_sys::CXIdxEntityRefInfo, tu: clang_sys::CXTranslationUnit) -> Self {
+        Self {
+            kind: EntityRefKind::from_raw(raw.kind),
+            cursor: Cursor::from_raw(raw.cursor),
+            location: idx_location(raw.loc, tu),
+            referenced: EntityInfo::from_raw(raw.referencedEntity)
+                .unwrap_or_else(|| unreachable!("reference without entity")),
+            parent: EntityInfo::from_raw(raw.parentEntity),
+            container: container(raw.container),
+            roles: SymbolRoles::from_raw(raw.role),
+        }
+    }
+}
+
+/// an `#include` or `#import` directive found by an [`IndexAction`]
+#[derive(Debug, Clone)]
+pub struct IncludedFileInfo<'tu> {
+    /// location of the `#`
+    pub hash_location: SourceLocation<'tu>,
+    /// the name as written in the directive, without quotes or angle brackets
+    pub filename: String,
+    /// `None` if the file cannot be found
+    pub file: Option<File<'tu>>,
+    pub is_import: bool,
+    pub is_angled: bool,
+    /// the directive was turned into a module import
+    pub is_module_import: bool,
+}
+
+impl<'tu> IncludedFileInfo<'tu> {
+    fn from_raw(raw: &clang_sys::CXIdxIncludedFileInfo, tu: clang_sys::CXTranslationUnit) -> Self {
+        Self {
+            hash_location: idx_location(raw.hashLoc, tu),
+            filename: unsafe { c_str_to_string(raw.filename) }.unwrap_or_default(),
+            file: File::from_raw(raw.file, tu),
+            is_import: raw.isImport != 0,
+            is_angled: raw.isAngled != 0,
+            is_module_import: raw.isModuleImport != 0,
+        }
+    }
+}
+
+/// an AST file, e.g. a precompiled header or a module, imported while indexing
+#[derive(Debug, Clone)]
+pub struct ImportedAstFileInfo<'tu> {
+    pub file: Option<File<'tu>>,
+    /// location of the import, invalid for a precompiled header
+    pub location: SourceLocation<'tu>,
+    /// the import was not written in the source, e.g. a module imported by an `#include`
+    pub is_implicit: bool,
+}
+
+impl<'tu> ImportedAstFileInfo<'tu> {
+    fn from_raw(
+        raw: &clang_sys::CXIdxImportedASTFileInfo,
+        tu: clang_sys::CXTranslationUnit,
+    ) -> Self {
+        Self {
+            file: File::from_raw(raw.file, tu),
+            location: idx_location(raw.loc, tu),
+            is_implicit: raw.isImplicit != 0,
+        }
+    }
+}
+
+/// receives what an [`IndexAction`] finds, every method does nothing by default
+///
+/// returning [`Visit::Break`] from any method aborts indexing, no method is called afterwards,
+/// a panic inside a method aborts indexing as well and is resumed once libclang returns
+///
+/// what the methods receive is only valid during the call
+///
+/// when indexing a source file, the translation unit only exists once libclang returns, so the
+/// [`File`]s and locations passed to `diagnostics`, `entered_main_file`, `included_file` and
+/// `imported_ast_file` carry no translation unit, e.g. [`File::contents`] is `None`
+pub trait IndexerCallbacks {
+    /// diagnostics of the whole translation unit, reported once indexing is done
+    fn diagnostics(&mut self, _diagnostics: Diagnostics<'_>) -> Visit {
+        Visit::Continue
+    }
+    fn entered_main_file(&mut self, _file: File<'_>) -> Visit {
+        Visit::Continue
+    }
+    fn included_file(&mut self, _info: &IncludedFileInfo<'_>) -> Visit {
+        Visit::Continue
+    }
+    fn imported_ast_file(&mut self, _info: &ImportedAstFileInfo<'_>) -> Visit {
+        Visit::Continue
+    }
+    /// parsing is done and declarations are about to be reported
+    fn started_translation_unit(&mut self) -> Visit {
+        Visit::Continue
+    }
+    fn declaration(&mut self, _info: &DeclInfo<'_>) -> Visit {
+        Visit::Continue
+    }
+    fn entity_reference(&mut self, _info: &EntityRefInfo<'_>) -> Visit {
+        Visit::Continue
+    }
+}
+
+struct State<'c, C> {
+    callbacks: &'c mut C,
+    /// null when indexing a source file, callbacks with a cursor take the translation unit from it
+    tu: clang_sys::CXTranslationUnit,
+    aborted: bool,
+    panic: CaughtPanic,
+}
+
+impl<'c, C: IndexerCallbacks> State<'c, C> {
+    fn new(callbacks: &'c mut C, tu: clang_sys::CXTranslationUnit) -> Self {
+        Self {
+            callbacks,
+            tu,
+            aborted: false,
+            panic: CaughtPanic::default(),
+        }
+    }
+
+    /// `data` is the client data passed to libclang
+    fn from_client_data<'a>(data: clang_sys::CXClientData) -> &'a mut Self {
+        unsafe { &mut *(data as *mut Self) }
+    }
+
+    fn call<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut C, clang_sys::CXTranslationUnit) -> Visit,
+    {
+        if self.aborted {
+            return;
+        }
+        let (callbacks, tu) = (&mut *self.callbacks, self.tu);
+        match self.panic.catch(|| f(callbacks, tu)) {
+            Some(Visit::Continue) => {}
+            Some(Visit::Break) | None => self.aborted = true,
+        }
+    }
+
+    fn resume_panic(self) {
+        self.panic.resume();
+    }
+
+    fn raw_callbacks() -> clang_sys::IndexerCallbacks {
+        extern "C" fn abort_query<C: IndexerCallbacks>(
+            data: clang_sys::CXClientData,
+            _: *mut c_void,
+        ) -> c_int {
+            c_int::from(State::<C>::from_client_data(data).aborted)
+        }
+        extern "C" fn diagnostic<C: IndexerCallbacks>(
+            data: clang_sys::CXClientData,
+            set: clang_sys::CXDiagnosticSet,
+            _: *mut c_void,
+        ) {
+            State::<C>::from_client_data(data)
+                .call(|callbacks, tu| callbacks.diagnostics(Diagnostics::from_set(set, tu)));
+        }
+        extern "C" fn entered_main_file<C: IndexerCallbacks>(
+            data: clang_sys::CXClientData,
+            file: clang_sys::CXFile,
+            _: *mut c_void,
+        ) -> clang_sys::CXIdxClientFile {
+            State::<C>::from_client_data(data).call(|callbacks, tu| {
+                File::from_raw(file, tu)
+                    .map_or(Visit::Continue, |file| callbacks.entered_main_file(file))
+            });
+            std::ptr::null_mut()
+        }
+        extern "C" fn included_file<C: IndexerCallbacks>(
+            data: clang_sys::CXClientData,
+            info: *const clang_sys::CXIdxIncludedFileInfo,
+        ) -> clang_sys::CXIdxClientFile {
+            let info = unsafe { &*info };
+            State::<C>::from_client_data(data).call(|callbacks, tu| {
+                callbacks.included_file(&IncludedFileInfo::from_raw(info, tu))
+            });
+            std::ptr::null_mut()
+        }
+        extern "C" fn imported_ast_file<C: IndexerCallbacks>(
+            data: clang_sys::CXClientData,
+            info: *const clang_sys::CXIdxImportedASTFileInfo,
+        ) -> clang_sys::CXIdxClientASTFile {
+            let info = unsafe { &*info };
+            State::<C>::from_client_data(data).call(|callbacks, tu| {
+                callbacks.imported_ast_file(&ImportedAstFileInfo::from_raw(info, tu))
+            });
+            std::ptr::null_mut()
+        }
+        extern "C" fn started_translation_unit<C: IndexerCallbacks>(
+            data: clang_sys::CXClientData,
+            _: *mut c_void,
+        ) -> clang_sys::CXIdxClientContainer {
+            State::<C>::from_client_data(data)
+                .call(|callbacks, _| callbacks.started_translation_unit());
+            std::ptr::null_mut()
+        }
+        extern "C" fn declaration<C: IndexerCallbacks>(
+            data: clang_sys::CXClientData,
+            info: *const clang_sys::CXIdxDeclInfo,
+        ) {
+            let info = unsafe { &*info };
+            let tu = unsafe { clang_sys::clang_Cursor_getTranslationUnit(info.cursor) };
+            State::<C>::from_client_data(data)
+                .call(|callbacks, _| callbacks.declaration(&DeclInfo::from_raw(info, tu)));
+        }
+        extern "C" fn entity_reference<C: IndexerCallbacks>(
+            data: clang_sys::CXClientData,
+            info: *const clang_sys::CXIdxEntityRefInfo,
+        ) {
+            let info = unsafe { &*info };
+            let tu = unsafe { clang_sys::clang_Cursor_getTranslationUnit(info.cursor) };
+            State::<C>::from_client_data(data).call(|callbacks, _| {
+                callbacks.entity_reference(&EntityRefInfo::from_raw(info, tu))
+            });
+        }
+
+        clang_sys::IndexerCallbacks {
+            abortQuery: Some(abort_query::<C>),
+            diagnostic: Some(diagnostic::<C>),
+            enteredMainFile: Some(entered_main_file::<C>),
+            ppIncludedFile: Some(included_file::<C>),
+            importedASTFile: Some(imported_ast_file::<C>),
+            startedTranslationUnit: Some(started_translation_unit::<C>),
+            indexDeclaration: Some(declaration::<C>),
+            indexEntityReference: Some(entity_reference::<C>),
+        }
+    }
+
+    fn as_client_data(&mut self) -> clang_sys::CXClientData {
+        self as *mut Self as clang_sys::CXClientData
+    }
+}
+
+/// reports declarations, references and includes of translation units to [`IndexerCallbacks`],
+/// including implicit references which cursor traversal does not see
+///
+/// an action can index many files, sharing work on common headers,
+/// see [`IndexOptions::SKIP_PARSED_BODIES_IN_SESSION`]
+#[derive(Debug)]
+pub struct IndexAction<'index> {
+    raw: clang_sys::CXIndexAction,
+    _index: PhantomData<&'index Index<'index>>,
+}
+
+impl<'index> Drop for IndexAction<'index> {
+    fn drop(&mut self) {
+        unsafe { clang_sys::clang_IndexAction_dispose(self.raw) };
+    }
+}
+
+impl<'clang> Index<'clang> {
+    /// # Panics
+    ///
+    /// it panics if libclang fails to create the action, see [`Index::try_create_index_action`]
+    pub fn create_index_action(&self) -> IndexAction<'_> {
+        self.try_create_index_action()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+    pub fn try_create_index_action(&self) -> Result<IndexAction<'_>> {
+        let raw = unsafe { clang_sys::clang_IndexAction_create(self.raw) };
+        if raw.is_null() {
+            return Err(Error::CreateIndexAction);
+        }
+        Ok(IndexAction {
+            raw,
+            _index: PhantomData,
+        })
+    }
+}
+
+impl<'index> IndexAction<'index> {
+    /// parse and index `path` like [`Index::parse`], returning the parsed translation unit
+    ///
+    /// the translation unit may be incomplete if indexing is aborted
+    pub fn index_source_file<P, S, C>(
+        &self,
+        path: P,
+        args: &[S],
+        unsaved_files: &[UnsavedFile],
+        parse_options: ParseOptions,
+        index_options: IndexOptions,
+        callbacks: &mut C,
+    ) -> Result<TranslationUnit<'index>>
+    where
+        P: AsRef<Path>,
+        S: AsRef<str>,
+        C: IndexerCallbacks,
+    {
+        let path = path_to_cstring(path)?;
+        let args = CStringArray::new(args)?;
+        let mut unsaved_files = RawUnsavedFiles::new(unsaved_files)?;
+        let mut raw_callbacks = State::<C>::raw_callbacks();
+        let mut state = State::new(callbacks, std::ptr::null_mut());
+        let mut raw = std::ptr::null_mut();
+        let code = unsafe {
+            clang_sys::clang_indexSourceFile(
+                self.raw,
+                state.as_client_data(),
+                &mut raw_callbacks,
+                std::mem::size_of::<clang_sys::IndexerCallbacks>() as u32,
+                index_options.bits(),
+                path.as_ptr(),
+                args.as_ptr(),
+                args.len() as i32,
+                unsaved_files.as_mut_ptr(),
+                unsaved_files.len(),
+                &mut raw,
+                parse_options.bits(),
+            )
+        };
+        // disposed if the panic is resumed
+        let tu = (!raw.is_null()).then(|| TranslationUnit::from_raw(raw));
+        state.resume_panic();
+        ParseError::from_error_code(code).map_err(Error::Index)?;
+        Ok(tu.unwrap_or_else(|| unreachable!("indexing succeeded without translation unit")))
+    }
+    /// index an already parsed translation unit
+    ///
+    /// stopping from a callback is not an error, only a failure of libclang is
+    pub fn index_translation_unit<C: IndexerCallbacks>(
+        &self,
+        tu: &TranslationUnit,
+        options: IndexOptions,
+        callbacks: &mut C,
+    ) -> Result<()> {
+        let mut raw_callbacks = State::<C>::raw_callbacks();
+        let mut state = State::new(callbacks, tu.raw);
+        let code = unsafe {
+            clang_sys::clang_indexTranslationUnit(
+                self.raw,
+                state.as_client_data(),
+                &mut raw_callbacks,
+                std::mem::size_of::<clang_sys::IndexerCallbacks>() as u32,
+                options.bits(),
+                tu.raw,
+            )
+        };
+        state.resume_panic();
+        ParseError::from_error_code(code).map_err(Error::Index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_small_value_struct(&IndexOptions::SUPPRESS_WARNINGS);
+        is_small_value_struct(&SymbolRoles::CALL);
+        is_small_value_enum(&EntityKind::Function);
+        is_small_value_enum(&EntityTemplateKind::Template);
+        is_small_value_enum(&EntityLanguage::CXX);
+        is_small_value_enum(&EntityRefKind::Direct);
+
+        let action = IndexAction {
+            raw: std::ptr::null_mut(),
+            _index: PhantomData,
+        };
+        is_ffi_struct(&action);
+        std::mem::forget(action);
+    }
+
+    #[test]
+    fn raw_values() {
+        assert_eq!(
+            EntityKind::from_raw(clang_sys::CXIdxEntity_CXXInstanceMethod),
+            EntityKind::CXXInstanceMethod
+        );
+        assert_eq!(EntityKind::from_raw(100_000), EntityKind::Unknown(100_000));
+        assert_eq!(
+            EntityTemplateKind::from_raw(100_000),
+            EntityTemplateKind::Unknown(100_000)
+        );
+        assert_eq!(
+            EntityLanguage::from_raw(100_000),
+            EntityLanguage::Unknown(100_000)
+        );
+        assert_eq!(
+            EntityRefKind::from_raw(100_000),
+            EntityRefKind::Unknown(100_000)
+        );
+        assert_eq!(
+            EntityRefKind::from_raw(clang_sys::CXIdxEntityRef_Implicit),
+            EntityRefKind::Implicit
+        );
+        assert_eq!(unsafe { c_str_to_string(std::ptr::null()) }, None);
+        assert_eq!(unsafe { c_str_to_string(c"".as_ptr()) }, None);
+        assert_eq!(
+            unsafe { c_str_to_string(c"add".as_ptr()) }.as_deref(),
+            Some("add")
+        );
+    }
+
+    #[derive(Default)]
+    struct Counter {
+        calls: usize,
+    }
+
+    impl IndexerCallbacks for Counter {
+        fn started_translation_unit(&mut self) -> Visit {
+            self.calls += 1;
+            match self.calls {
+                1 => Visit::Continue,
+                2 => Visit::Break,
+                _ => panic!("called after abort"),
+            }
+        }
+    }
+
+    #[test]
+    fn abort_and_panic() {
+        let mut counter = Counter::default();
+        let mut state = State::new(&mut counter, std::ptr::null_mut());
+        let data = state.as_client_data();
+        let callbacks = State::<Counter>::raw_callbacks();
+        let started = callbacks.startedTranslationUnit.unwrap();
+        let abort = callbacks.abortQuery.unwrap();
+
+        started(data, std::ptr::null_mut());
+        assert_eq!(abort(data, std::ptr::null_mut()), 0);
+        started(data, std::ptr::null_mut());
+        assert_eq!(abort(data, std::ptr::null_mut()), 1);
+        started(data, std::ptr::null_mut());
+        // nothing to resume
+        state.resume_panic();
+        assert_eq!(counter.calls, 2);
+
+        struct Panicking;
+        impl IndexerCallbacks for Panicking {
+            fn started_translation_unit(&mut self) -> Visit {
+                panic!("oops")
+            }
+        }
+        let mut panicking = Panicking;
+        let mut state = State::new(&mut panicking, std::ptr::null_mut());
+        let started = State::<Panicking>::raw_callbacks()
+            .startedTranslationUnit
+            .unwrap();
+        started(state.as_client_data(), std::ptr::null_mut());
+        assert!(state.aborted);
+        let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| state.resume_panic()));
+        assert_eq!(panic.unwrap_err().downcast_ref::<&str>(), Some(&"oops"));
+    }
+}
@@ -0,0 +1,151 @@
+use std::marker::PhantomData;
+
+use super::{Cursor, TranslationUnit};
+use crate::utility::cxstring_into_string;
+
+/// how declarations are printed by [`Cursor::pretty_print`], wraps `CXPrintingPolicy`
+///
+/// it starts with the settings of the translation unit the cursor belongs to,
+/// see [`Cursor::printing_policy`]
+#[derive(Debug)]
+pub struct PrintingPolicy<'tu> {
+    raw: clang_sys::CXPrintingPolicy,
+    _tu: PhantomData<&'tu TranslationUnit<'tu>>,
+}
+
+impl<'tu> Drop for PrintingPolicy<'tu> {
+    fn drop(&mut self) {
+        unsafe { clang_sys::clang_PrintingPolicy_dispose(self.raw) };
+    }
+}
+
+impl<'tu> PrintingPolicy<'tu> {
+    fn get(&self, property: clang_sys::CXPrintingPolicyProperty) -> u32 {
+        unsafe { clang_sys::clang_PrintingPolicy_getProperty(self.raw, property) }
+    }
+    fn set(&mut self, property: clang_sys::CXPrintingPolicyProperty, value: u32) {
+        unsafe { clang_sys::clang_PrintingPolicy_setProperty(self.raw, property, value) };
+    }
+
+    /// number of spaces per indentation level
+    pub fn indentation(&self) -> u32 {
+        self.get(clang_sys::CXPrintingPolicy_Indentation)
+    }
+    pub fn set_indentation(&mut self, value: u32) {
+        self.set(clang_sys::CXPrintingPolicy_Indentation, value);
+    }
+}
+
+/// a getter and a setter for each boolean `CXPrintingPolicyProperty`
+macro_rules! bool_properties {
+    ($(
+        $(#[$meta:meta])*
+        $get:ident, $set:ident = $property:ident;
+    )+) => {
+        impl<'tu> PrintingPolicy<'tu> {
+            $(
+                $(#[$meta])*
+                pub fn $get(&self) -> bool {
+                    self.get(clang_sys::$property) != 0
+                }
+                pub fn $set(&mut self, value: bool) {
+                    self.set(clang_sys::$property, u32::from(value));
+                }
+            )+
+        }
+    };
+}
+
+bool_properties! {
+    /// leave out specifiers and the type, as for `b` in `int a, b;`
+    suppress_specifiers, set_suppress_specifiers = CXPrintingPolicy_SuppressSpecifiers;
+    /// print `S` instead of `struct S`
+    suppress_tag_keyword, set_suppress_tag_keyword = CXPrintingPolicy_SuppressTagKeyword;
+    /// print the definition of a tag declared inline, e.g. in `typedef struct { int x; } S;`
+    include_tag_definition, set_include_tag_definition = CXPrintingPolicy_IncludeTagDefinition;
+    /// leave out enclosing namespaces and classes
+    suppress_scope, set_suppress_scope = CXPrintingPolicy_SuppressScope;
+    /// leave out anonymous and inline namespaces
+    suppress_unwritten_scope, set_suppress_unwritten_scope = CXPrintingPolicy_SuppressUnwrittenScope;
+    /// leave out initializers of variables
+    suppress_initializers, set_suppress_initializers = CXPrintingPolicy_SuppressInitializers;
+    /// print array sizes as written, e.g. `N` instead of `3`
+    constant_array_size_as_written, set_constant_array_size_as_written = CXPrintingPolicy_ConstantArraySizeAsWritten;
+    /// print the location of anonymous tags, e.g. `(anonymous struct at a.cpp:1:1)`
+    anonymous_tag_locations, set_anonymous_tag_locations = CXPrintingPolicy_AnonymousTagLocations;
+    /// leave out Objective-C `__strong`
+    suppress_strong_lifetime, set_suppress_strong_lifetime = CXPrintingPolicy_SuppressStrongLifetime;
+    /// leave out Objective-C lifetime qualifiers
+    suppress_lifetime_qualifiers, set_suppress_lifetime_qualifiers = CXPrintingPolicy_SuppressLifetimeQualifiers;
+    /// print `S` instead of `S<T>` for constructors of class templates
+    suppress_template_args_in_cxx_constructors, set_suppress_template_args_in_cxx_constructors = CXPrintingPolicy_SuppressTemplateArgsInCXXConstructors;
+    /// print `bool` instead of `_Bool`
+    use_bool, set_use_bool = CXPrintingPolicy_Bool;
+    /// print `restrict` instead of `__restrict`
+    use_restrict, set_use_restrict = CXPrintingPolicy_Restrict;
+    /// print `alignof` instead of `__alignof`
+    use_alignof, set_use_alignof = CXPrintingPolicy_Alignof;
+    /// print `_Alignof` instead of `__alignof`
+    use_underscore_alignof, set_use_underscore_alignof = CXPrintingPolicy_UnderscoreAlignof;
+    /// print `f(void)` instead of `f()`
+    use_void_for_zero_params, set_use_void_for_zero_params = CXPrintingPolicy_UseVoidForZeroParams;
+    /// leave out bodies of functions and members of classes
+    terse_output, set_terse_output = CXPrintingPolicy_TerseOutput;
+    /// leave out attributes, for declarations shown in documentation
+    polish_for_declaration, set_polish_for_declaration = CXPrintingPolicy_PolishForDeclaration;
+    /// print `half` instead of `__fp16`
+    use_half, set_use_half = CXPrintingPolicy_Half;
+    /// print `wchar_t` as `__wchar_t`
+    use_ms_wchar, set_use_ms_wchar = CXPrintingPolicy_MSWChar;
+    /// print newlines after statements like `break`
+    include_newlines, set_include_newlines = CXPrintingPolicy_IncludeNewlines;
+    /// print names like MSVC does, e.g. `` `anonymous namespace' ``
+    msvc_formatting, set_msvc_formatting = CXPrintingPolicy_MSVCFormatting;
+    /// print constants as written, e.g. `0x10` instead of `16`
+    constants_as_written, set_constants_as_written = CXPrintingPolicy_ConstantsAsWritten;
+    /// leave out the implicit `this` of member accesses
+    suppress_implicit_base, set_suppress_implicit_base = CXPrintingPolicy_SuppressImplicitBase;
+    /// print fully qualified names, e.g. `ns::S` instead of `S`
+    fully_qualified_name, set_fully_qualified_name = CXPrintingPolicy_FullyQualifiedName;
+}
+
+impl<'tu> Cursor<'tu> {
+    /// the default policy of the translation unit, to be adjusted and passed to [`Cursor::pretty_print`]
+    pub fn printing_policy(&self) -> PrintingPolicy<'tu> {
+        let raw = unsafe { clang_sys::clang_getCursorPrintingPolicy(self.raw) };
+        assert!(!raw.is_null());
+        PrintingPolicy {
+            raw,
+            _tu: PhantomData,
+        }
+    }
+    /// the declaration as source text, e.g. `int add(int a, int b)`
+    pub fn pretty_print(&self, policy: &PrintingPolicy) -> String {
+        unsafe {
+            cxstring_into_string(clang_sys::clang_getCursorPrettyPrinted(
+                self.raw, policy.raw,
+            ))
+        }
+    }
+    /// the name with extra information, e.g. the parameter types of a function as in `add(int, int)`
+    pub fn display_name(&self) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_getCursorDisplayName(self.raw)) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        let policy = PrintingPolicy {
+            raw: std::ptr::null_mut(),
+            _tu: PhantomData,
+        };
+        is_ffi_struct(&policy);
+        std::mem::forget(policy);
+    }
+}
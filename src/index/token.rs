@@ -0,0 +1,159 @@
+use std::marker::PhantomData;
+
+use super::{Cursor, SourceLocation, SourceRange, TranslationUnit};
+use crate::utility::cxstring_into_string;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TokenKind {
+    Punctuation,
+    Keyword,
+    Identifier,
+    Literal,
+    Comment,
+}
+
+impl TokenKind {
+    fn from_raw(raw: clang_sys::CXTokenKind) -> Self {
+        match raw {
+            clang_sys::CXToken_Punctuation => Self::Punctuation,
+            clang_sys::CXToken_Keyword => Self::Keyword,
+            clang_sys::CXToken_Identifier => Self::Identifier,
+            clang_sys::CXToken_Literal => Self::Literal,
+            clang_sys::CXToken_Comment => Self::Comment,
+            k => unreachable!("unexpected CXTokenKind {}", k),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Token<'tu> {
+    raw: clang_sys::CXToken,
+    tu: clang_sys::CXTranslationUnit,
+    _tu: PhantomData<&'tu TranslationUnit<'tu>>,
+}
+
+impl<'tu> Token<'tu> {
+    pub fn kind(&self) -> TokenKind {
+        TokenKind::from_raw(unsafe { clang_sys::clang_getTokenKind(self.raw) })
+    }
+    pub fn spelling(&self) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_getTokenSpelling(self.tu, self.raw)) }
+    }
+    pub fn location(&self) -> SourceLocation<'_> {
+        SourceLocation::from_raw(
+            unsafe { clang_sys::clang_getTokenLocation(self.tu, self.raw) },
+            self.tu,
+        )
+    }
+    pub fn extent(&self) -> SourceRange<'_> {
+        SourceRange::from_raw(
+            unsafe { clang_sys::clang_getTokenExtent(self.tu, self.raw) },
+            self.tu,
+        )
+    }
+}
+
+/// tokens owned by a translation unit, see [`TranslationUnit::tokenize`]
+#[derive(Debug)]
+pub struct Tokens<'tu> {
+    raw: *mut clang_sys::CXToken,
+    len: u32,
+    tu: clang_sys::CXTranslationUnit,
+    _tu: PhantomData<&'tu TranslationUnit<'tu>>,
+}
+
+impl<'tu> Drop for Tokens<'tu> {
+    fn drop(&mut self) {
+        if !self.raw.is_null() {
+            unsafe { clang_sys::clang_disposeTokens(self.tu, self.raw, self.len) };
+        }
+    }
+}
+
+impl<'tu> Tokens<'tu> {
+    fn as_slice(&self) -> &[clang_sys::CXToken] {
+        if self.raw.is_null() {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.raw, self.len as usize) }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn get(&self, index: usize) -> Option<Token<'tu>> {
+        self.as_slice().get(index).map(|&raw| Token {
+            raw,
+            tu: self.tu,
+            _tu: PhantomData,
+        })
+    }
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Token<'tu>> + '_ {
+        self.as_slice().iter().map(|&raw| Token {
+            raw,
+            tu: self.tu,
+            _tu: PhantomData,
+        })
+    }
+    /// the cursor each token belongs to, `None` if there is no such cursor
+    pub fn annotate(&self) -> Vec<Option<Cursor<'tu>>> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        let mut cursors = vec![clang_sys::CXCursor::default(); self.len()];
+        unsafe {
+            clang_sys::clang_annotateTokens(self.tu, self.raw, self.len, cursors.as_mut_ptr())
+        };
+        cursors.into_iter().map(Cursor::from_raw_valid).collect()
+    }
+}
+
+impl<'index> TranslationUnit<'index> {
+    /// all tokens within `range`
+    pub fn tokenize(&self, range: &SourceRange) -> Tokens<'_> {
+        let mut raw = std::ptr::null_mut();
+        let mut len = 0;
+        unsafe { clang_sys::clang_tokenize(self.raw, range.raw, &mut raw, &mut len) };
+        Tokens {
+            raw,
+            len,
+            tu: self.raw,
+            _tu: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_small_value_enum(&TokenKind::Keyword);
+
+        let tokens = Tokens {
+            raw: std::ptr::null_mut(),
+            len: 0,
+            tu: std::ptr::null_mut(),
+            _tu: PhantomData,
+        };
+        is_ffi_struct(&tokens);
+        assert!(tokens.is_empty());
+        assert!(tokens.get(0).is_none());
+        assert_eq!(tokens.iter().len(), 0);
+        assert!(tokens.annotate().is_empty());
+
+        let token = Token {
+            raw: clang_sys::CXToken::default(),
+            tu: std::ptr::null_mut(),
+            _tu: PhantomData,
+        };
+        is_ffi_struct(&token);
+    }
+}
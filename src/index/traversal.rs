@@ -0,0 +1,180 @@
+use std::collections::VecDeque;
+
+use super::{ChildVisit, Cursor};
+
+/// depth-first iterator over all descendants of a cursor, see [`Cursor::descendants`]
+#[derive(Debug)]
+pub struct Descendants<'tu> {
+    stack: Vec<(usize, Cursor<'tu>)>,
+    /// the last yielded cursor, its children are pushed lazily so they can be skipped
+    last: Option<(usize, Cursor<'tu>)>,
+}
+
+impl<'tu> Descendants<'tu> {
+    /// do not descend into children of the cursor yielded last
+    pub fn skip_subtree(&mut self) {
+        self.last = None;
+    }
+}
+
+impl<'tu> Iterator for Descendants<'tu> {
+    type Item = (usize, Cursor<'tu>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((depth, cursor)) = self.last.take() {
+            let children = cursor.children();
+            self.stack
+                .extend(children.into_iter().rev().map(|c| (depth + 1, c)));
+        }
+        let next = self.stack.pop()?;
+        self.last = Some(next);
+        Some(next)
+    }
+}
+
+/// breadth-first iterator over all descendants of a cursor, see [`Cursor::descendants_breadth_first`]
+#[derive(Debug)]
+pub struct BreadthFirstDescendants<'tu> {
+    queue: VecDeque<(usize, Cursor<'tu>)>,
+    /// the last yielded cursor, its children are queued lazily so they can be skipped
+    last: Option<(usize, Cursor<'tu>)>,
+}
+
+impl<'tu> BreadthFirstDescendants<'tu> {
+    /// do not queue children of the cursor yielded last
+    pub fn skip_subtree(&mut self) {
+        self.last = None;
+    }
+}
+
+impl<'tu> Iterator for BreadthFirstDescendants<'tu> {
+    type Item = (usize, Cursor<'tu>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((depth, cursor)) = self.last.take() {
+            let children = cursor.children();
+            self.queue
+                .extend(children.into_iter().map(|c| (depth + 1, c)));
+        }
+        let next = self.queue.pop_front()?;
+        self.last = Some(next);
+        Some(next)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+enum Parent {
+    Semantic,
+    Lexical,
+}
+
+/// iterator over the parents of a cursor up to the translation unit,
+/// see [`Cursor::ancestors`] and [`Cursor::lexical_ancestors`]
+#[derive(Debug)]
+pub struct Ancestors<'tu> {
+    current: Option<Cursor<'tu>>,
+    parent: Parent,
+}
+
+impl<'tu> Iterator for Ancestors<'tu> {
+    type Item = Cursor<'tu>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.take()?;
+        self.current = match self.parent {
+            Parent::Semantic => current.semantic_parent(),
+            Parent::Lexical => current.lexical_parent(),
+        };
+        self.current
+    }
+}
+
+impl<'tu> Cursor<'tu> {
+    /// direct children of this cursor
+    pub fn children(&self) -> Vec<Cursor<'tu>> {
+        let mut children = Vec::new();
+        self.visit_children(|cursor, _parent| {
+            children.push(cursor);
+            ChildVisit::Continue
+        });
+        children
+    }
+    /// all descendants in depth-first pre-order, direct children have depth `1`
+    pub fn descendants(&self) -> Descendants<'tu> {
+        Descendants {
+            stack: Vec::new(),
+            last: Some((0, *self)),
+        }
+    }
+    /// all descendants in breadth-first order, direct children have depth `1`
+    pub fn descendants_breadth_first(&self) -> BreadthFirstDescendants<'tu> {
+        BreadthFirstDescendants {
+            queue: VecDeque::new(),
+            last: Some((0, *self)),
+        }
+    }
+    /// semantic parents, from the closest one up to the translation unit
+    pub fn ancestors(&self) -> Ancestors<'tu> {
+        Ancestors {
+            current: Some(*self),
+            parent: Parent::Semantic,
+        }
+    }
+    /// lexical parents, from the closest one up to the translation unit
+    pub fn lexical_ancestors(&self) -> Ancestors<'tu> {
+        Ancestors {
+            current: Some(*self),
+            parent: Parent::Lexical,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        let descendants = Descendants {
+            stack: Vec::new(),
+            last: None,
+        };
+        is_ffi_struct(&descendants);
+
+        let breadth_first = BreadthFirstDescendants {
+            queue: VecDeque::new(),
+            last: None,
+        };
+        is_ffi_struct(&breadth_first);
+
+        let ancestors = Ancestors {
+            current: None,
+            parent: Parent::Semantic,
+        };
+        is_ffi_struct(&ancestors);
+        is_small_value_enum(&Parent::Lexical);
+    }
+
+    #[test]
+    fn exhausted_iterators_yield_nothing() {
+        let mut descendants = Descendants {
+            stack: Vec::new(),
+            last: None,
+        };
+        assert!(descendants.next().is_none());
+
+        let mut breadth_first = BreadthFirstDescendants {
+            queue: VecDeque::new(),
+            last: None,
+        };
+        assert!(breadth_first.next().is_none());
+
+        let mut ancestors = Ancestors {
+            current: None,
+            parent: Parent::Lexical,
+        };
+        assert!(ancestors.next().is_none());
+    }
+}
@@ -0,0 +1,482 @@
+use std::marker::PhantomData;
+
+use super::{Cursor, TranslationUnit};
+use crate::utility::{cxstring_into_string, str_to_cstring};
+
+/// Kind of a [`Type`], mirrors `CXTypeKind`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(i32)]
+#[non_exhaustive]
+pub enum TypeKind {
+    Invalid = clang_sys::CXType_Invalid,
+    Unexposed = clang_sys::CXType_Unexposed,
+    Void = clang_sys::CXType_Void,
+    Bool = clang_sys::CXType_Bool,
+    CharU = clang_sys::CXType_Char_U,
+    UChar = clang_sys::CXType_UChar,
+    Char16 = clang_sys::CXType_Char16,
+    Char32 = clang_sys::CXType_Char32,
+    UShort = clang_sys::CXType_UShort,
+    UInt = clang_sys::CXType_UInt,
+    ULong = clang_sys::CXType_ULong,
+    ULongLong = clang_sys::CXType_ULongLong,
+    UInt128 = clang_sys::CXType_UInt128,
+    CharS = clang_sys::CXType_Char_S,
+    SChar = clang_sys::CXType_SChar,
+    WChar = clang_sys::CXType_WChar,
+    Short = clang_sys::CXType_Short,
+    Int = clang_sys::CXType_Int,
+    Long = clang_sys::CXType_Long,
+    LongLong = clang_sys::CXType_LongLong,
+    Int128 = clang_sys::CXType_Int128,
+    Float = clang_sys::CXType_Float,
+    Double = clang_sys::CXType_Double,
+    LongDouble = clang_sys::CXType_LongDouble,
+    NullPtr = clang_sys::CXType_NullPtr,
+    Overload = clang_sys::CXType_Overload,
+    Dependent = clang_sys::CXType_Dependent,
+    ObjCId = clang_sys::CXType_ObjCId,
+    ObjCClass = clang_sys::CXType_ObjCClass,
+    ObjCSel = clang_sys::CXType_ObjCSel,
+    Float128 = clang_sys::CXType_Float128,
+    Half = clang_sys::CXType_Half,
+    Float16 = clang_sys::CXType_Float16,
+    ShortAccum = clang_sys::CXType_ShortAccum,
+    Accum = clang_sys::CXType_Accum,
+    LongAccum = clang_sys::CXType_LongAccum,
+    UShortAccum = clang_sys::CXType_UShortAccum,
+    UAccum = clang_sys::CXType_UAccum,
+    ULongAccum = clang_sys::CXType_ULongAccum,
+    BFloat16 = clang_sys::CXType_BFloat16,
+    Ibm128 = clang_sys::CXType_Ibm128,
+    Complex = clang_sys::CXType_Complex,
+    Pointer = clang_sys::CXType_Pointer,
+    BlockPointer = clang_sys::CXType_BlockPointer,
+    LValueReference = clang_sys::CXType_LValueReference,
+    RValueReference = clang_sys::CXType_RValueReference,
+    Record = clang_sys::CXType_Record,
+    Enum = clang_sys::CXType_Enum,
+    Typedef = clang_sys::CXType_Typedef,
+    ObjCInterface = clang_sys::CXType_ObjCInterface,
+    ObjCObjectPointer = clang_sys::CXType_ObjCObjectPointer,
+    FunctionNoProto = clang_sys::CXType_FunctionNoProto,
+    FunctionProto = clang_sys::CXType_FunctionProto,
+    ConstantArray = clang_sys::CXType_ConstantArray,
+    Vector = clang_sys::CXType_Vector,
+    IncompleteArray = clang_sys::CXType_IncompleteArray,
+    VariableArray = clang_sys::CXType_VariableArray,
+    DependentSizedArray = clang_sys::CXType_DependentSizedArray,
+    MemberPointer = clang_sys::CXType_MemberPointer,
+    Auto = clang_sys::CXType_Auto,
+    Elaborated = clang_sys::CXType_Elaborated,
+    Pipe = clang_sys::CXType_Pipe,
+    OCLImage1dRO = clang_sys::CXType_OCLImage1dRO,
+    OCLImage1dArrayRO = clang_sys::CXType_OCLImage1dArrayRO,
+    OCLImage1dBufferRO = clang_sys::CXType_OCLImage1dBufferRO,
+    OCLImage2dRO = clang_sys::CXType_OCLImage2dRO,
+    OCLImage2dArrayRO = clang_sys::CXType_OCLImage2dArrayRO,
+    OCLImage2dDepthRO = clang_sys::CXType_OCLImage2dDepthRO,
+    OCLImage2dArrayDepthRO = clang_sys::CXType_OCLImage2dArrayDepthRO,
+    OCLImage2dMSAARO = clang_sys::CXType_OCLImage2dMSAARO,
+    OCLImage2dArrayMSAARO = clang_sys::CXType_OCLImage2dArrayMSAARO,
+    OCLImage2dMSAADepthRO = clang_sys::CXType_OCLImage2dMSAADepthRO,
+    OCLImage2dArrayMSAADepthRO = clang_sys::CXType_OCLImage2dArrayMSAADepthRO,
+    OCLImage3dRO = clang_sys::CXType_OCLImage3dRO,
+    OCLImage1dWO = clang_sys::CXType_OCLImage1dWO,
+    OCLImage1dArrayWO = clang_sys::CXType_OCLImage1dArrayWO,
+    OCLImage1dBufferWO = clang_sys::CXType_OCLImage1dBufferWO,
+    OCLImage2dWO = clang_sys::CXType_OCLImage2dWO,
+    OCLImage2dArrayWO = clang_sys::CXType_OCLImage2dArrayWO,
+    OCLImage2dDepthWO = clang_sys::CXType_OCLImage2dDepthWO,
+    OCLImage2dArrayDepthWO = clang_sys::CXType_OCLImage2dArrayDepthWO,
+    OCLImage2dMSAAWO = clang_sys::CXType_OCLImage2dMSAAWO,
+    OCLImage2dArrayMSAAWO = clang_sys::CXType_OCLImage2dArrayMSAAWO,
+    OCLImage2dMSAADepthWO = clang_sys::CXType_OCLImage2dMSAADepthWO,
+    OCLImage2dArrayMSAADepthWO = clang_sys::CXType_OCLImage2dArrayMSAADepthWO,
+    OCLImage3dWO = clang_sys::CXType_OCLImage3dWO,
+    OCLImage1dRW = clang_sys::CXType_OCLImage1dRW,
+    OCLImage1dArrayRW = clang_sys::CXType_OCLImage1dArrayRW,
+    OCLImage1dBufferRW = clang_sys::CXType_OCLImage1dBufferRW,
+    OCLImage2dRW = clang_sys::CXType_OCLImage2dRW,
+    OCLImage2dArrayRW = clang_sys::CXType_OCLImage2dArrayRW,
+    OCLImage2dDepthRW = clang_sys::CXType_OCLImage2dDepthRW,
+    OCLImage2dArrayDepthRW = clang_sys::CXType_OCLImage2dArrayDepthRW,
+    OCLImage2dMSAARW = clang_sys::CXType_OCLImage2dMSAARW,
+    OCLImage2dArrayMSAARW = clang_sys::CXType_OCLImage2dArrayMSAARW,
+    OCLImage2dMSAADepthRW = clang_sys::CXType_OCLImage2dMSAADepthRW,
+    OCLImage2dArrayMSAADepthRW = clang_sys::CXType_OCLImage2dArrayMSAADepthRW,
+    OCLImage3dRW = clang_sys::CXType_OCLImage3dRW,
+    OCLSampler = clang_sys::CXType_OCLSampler,
+    OCLEvent = clang_sys::CXType_OCLEvent,
+    OCLQueue = clang_sys::CXType_OCLQueue,
+    OCLReserveID = clang_sys::CXType_OCLReserveID,
+    ObjCObject = clang_sys::CXType_ObjCObject,
+    ObjCTypeParam = clang_sys::CXType_ObjCTypeParam,
+    Attributed = clang_sys::CXType_Attributed,
+    OCLIntelSubgroupAVCMcePayload = clang_sys::CXType_OCLIntelSubgroupAVCMcePayload,
+    OCLIntelSubgroupAVCImePayload = clang_sys::CXType_OCLIntelSubgroupAVCImePayload,
+    OCLIntelSubgroupAVCRefPayload = clang_sys::CXType_OCLIntelSubgroupAVCRefPayload,
+    OCLIntelSubgroupAVCSicPayload = clang_sys::CXType_OCLIntelSubgroupAVCSicPayload,
+    OCLIntelSubgroupAVCMceResult = clang_sys::CXType_OCLIntelSubgroupAVCMceResult,
+    OCLIntelSubgroupAVCImeResult = clang_sys::CXType_OCLIntelSubgroupAVCImeResult,
+    OCLIntelSubgroupAVCRefResult = clang_sys::CXType_OCLIntelSubgroupAVCRefResult,
+    OCLIntelSubgroupAVCSicResult = clang_sys::CXType_OCLIntelSubgroupAVCSicResult,
+    OCLIntelSubgroupAVCImeResultSingleRefStreamout =
+        clang_sys::CXType_OCLIntelSubgroupAVCImeResultSingleRefStreamout,
+    OCLIntelSubgroupAVCImeResultDualRefStreamout =
+        clang_sys::CXType_OCLIntelSubgroupAVCImeResultDualRefStreamout,
+    OCLIntelSubgroupAVCImeSingleRefStreamin =
+        clang_sys::CXType_OCLIntelSubgroupAVCImeSingleRefStreamin,
+    OCLIntelSubgroupAVCImeDualRefStreamin = clang_sys::CXType_OCLIntelSubgroupAVCImeDualRefStreamin,
+    ExtVector = clang_sys::CXType_ExtVector,
+    Atomic = clang_sys::CXType_Atomic,
+    BTFTagAttributed = clang_sys::CXType_BTFTagAttributed,
+    HLSLResource = clang_sys::CXType_HLSLResource,
+    HLSLAttributedResource = clang_sys::CXType_HLSLAttributedResource,
+    /// a kind added to libclang after this crate, with its raw `CXTypeKind` value
+    Unknown(i32),
+}
+
+impl TypeKind {
+    pub(crate) fn from_raw(raw: clang_sys::CXTypeKind) -> Self {
+        match raw {
+            clang_sys::CXType_Invalid => Self::Invalid,
+            clang_sys::CXType_Unexposed => Self::Unexposed,
+            clang_sys::CXType_Void => Self::Void,
+            clang_sys::CXType_Bool => Self::Bool,
+            clang_sys::CXType_Char_U => Self::CharU,
+            clang_sys::CXType_UChar => Self::UChar,
+            clang_sys::CXType_Char16 => Self::Char16,
+            clang_sys::CXType_Char32 => Self::Char32,
+            clang_sys::CXType_UShort => Self::UShort,
+            clang_sys::CXType_UInt => Self::UInt,
+            clang_sys::CXType_ULong => Self::ULong,
+            clang_sys::CXType_ULongLong => Self::ULongLong,
+            clang_sys::CXType_UInt128 => Self::UInt128,
+            clang_sys::CXType_Char_S => Self::CharS,
+            clang_sys::CXType_SChar => Self::SChar,
+            clang_sys::CXType_WChar => Self::WChar,
+            clang_sys::CXType_Short => Self::Short,
+            clang_sys::CXType_Int => Self::Int,
+            clang_sys::CXType_Long => Self::Long,
+            clang_sys::CXType_LongLong => Self::LongLong,
+            clang_sys::CXType_Int128 => Self::Int128,
+            clang_sys::CXType_Float => Self::Float,
+            clang_sys::CXType_Double => Self::Double,
+            clang_sys::CXType_LongDouble => Self::LongDouble,
+            clang_sys::CXType_NullPtr => Self::NullPtr,
+            clang_sys::CXType_Overload => Self::Overload,
+            clang_sys::CXType_Dependent => Self::Dependent,
+            clang_sys::CXType_ObjCId => Self::ObjCId,
+            clang_sys::CXType_ObjCClass => Self::ObjCClass,
+            clang_sys::CXType_ObjCSel => Self::ObjCSel,
+            clang_sys::CXType_Float128 => Self::Float128,
+            clang_sys::CXType_Half => Self::Half,
+            clang_sys::CXType_Float16 => Self::Float16,
+            clang_sys::CXType_ShortAccum => Self::ShortAccum,
+            clang_sys::CXType_Accum => Self::Accum,
+            clang_sys::CXType_LongAccum => Self::LongAccum,
+            clang_sys::CXType_UShortAccum => Self::UShortAccum,
+            clang_sys::CXType_UAccum => Self::UAccum,
+            clang_sys::CXType_ULongAccum => Self::ULongAccum,
+            clang_sys::CXType_BFloat16 => Self::BFloat16,
+            clang_sys::CXType_Ibm128 => Self::Ibm128,
+            clang_sys::CXType_Complex => Self::Complex,
+            clang_sys::CXType_Pointer => Self::Pointer,
+            clang_sys::CXType_BlockPointer => Self::BlockPointer,
+            clang_sys::CXType_LValueReference => Self::LValueReference,
+            clang_sys::CXType_RValueReference => Self::RValueReference,
+            clang_sys::CXType_Record => Self::Record,
+            clang_sys::CXType_Enum => Self::Enum,
+            clang_sys::CXType_Typedef => Self::Typedef,
+            clang_sys::CXType_ObjCInterface => Self::ObjCInterface,
+            clang_sys::CXType_ObjCObjectPointer => Self::ObjCObjectPointer,
+            clang_sys::CXType_FunctionNoProto => Self::FunctionNoProto,
+            clang_sys::CXType_FunctionProto => Self::FunctionProto,
+            clang_sys::CXType_ConstantArray => Self::ConstantArray,
+            clang_sys::CXType_Vector => Self::Vector,
+            clang_sys::CXType_IncompleteArray => Self::IncompleteArray,
+            clang_sys::CXType_VariableArray => Self::VariableArray,
+            clang_sys::CXType_DependentSizedArray => Self::DependentSizedArray,
+            clang_sys::CXType_MemberPointer => Self::MemberPointer,
+            clang_sys::CXType_Auto => Self::Auto,
+            clang_sys::CXType_Elaborated => Self::Elaborated,
+            clang_sys::CXType_Pipe => Self::Pipe,
+            clang_sys::CXType_OCLImage1dRO => Self::OCLImage1dRO,
+            clang_sys::CXType_OCLImage1dArrayRO => Self::OCLImage1dArrayRO,
+            clang_sys::CXType_OCLImage1dBufferRO => Self::OCLImage1dBufferRO,
+            clang_sys::CXType_OCLImage2dRO => Self::OCLImage2dRO,
+            clang_sys::CXType_OCLImage2dArrayRO => Self::OCLImage2dArrayRO,
+            clang_sys::CXType_OCLImage2dDepthRO => Self::OCLImage2dDepthRO,
+            clang_sys::CXType_OCLImage2dArrayDepthRO => Self::OCLImage2dArrayDepthRO,
+            clang_sys::CXType_OCLImage2dMSAARO => Self::OCLImage2dMSAARO,
+            clang_sys::CXType_OCLImage2dArrayMSAARO => Self::OCLImage2dArrayMSAARO,
+            clang_sys::CXType_OCLImage2dMSAADepthRO => Self::OCLImage2dMSAADepthRO,
+            clang_sys::CXType_OCLImage2dArrayMSAADepthRO => Self::OCLImage2dArrayMSAADepthRO,
+            clang_sys::CXType_OCLImage3dRO => Self::OCLImage3dRO,
+            clang_sys::CXType_OCLImage1dWO => Self::OCLImage1dWO,
+            clang_sys::CXType_OCLImage1dArrayWO => Self::OCLImage1dArrayWO,
+            clang_sys::CXType_OCLImage1dBufferWO => Self::OCLImage1dBufferWO,
+            clang_sys::CXType_OCLImage2dWO => Self::OCLImage2dWO,
+            clang_sys::CXType_OCLImage2dArrayWO => Self::OCLImage2dArrayWO,
+            clang_sys::CXType_OCLImage2dDepthWO => Self::OCLImage2dDepthWO,
+            clang_sys::CXType_OCLImage2dArrayDepthWO => Self::OCLImage2dArrayDepthWO,
+            clang_sys::CXType_OCLImage2dMSAAWO => Self::OCLImage2dMSAAWO,
+            clang_sys::CXType_OCLImage2dArrayMSAAWO => Self::OCLImage2dArrayMSAAWO,
+            clang_sys::CXType_OCLImage2dMSAADepthWO => Self::OCLImage2dMSAADepthWO,
+            clang_sys::CXType_OCLImage2dArrayMSAADepthWO => Self::OCLImage2dArrayMSAADepthWO,
+            clang_sys::CXType_OCLImage3dWO => Self::OCLImage3dWO,
+            clang_sys::CXType_OCLImage1dRW => Self::OCLImage1dRW,
+            clang_sys::CXType_OCLImage1dArrayRW => Self::OCLImage1dArrayRW,
+            clang_sys::CXType_OCLImage1dBufferRW => Self::OCLImage1dBufferRW,
+            clang_sys::CXType_OCLImage2dRW => Self::OCLImage2dRW,
+            clang_sys::CXType_OCLImage2dArrayRW => Self::OCLImage2dArrayRW,
+            clang_sys::CXType_OCLImage2dDepthRW => Self::OCLImage2dDepthRW,
+            clang_sys::CXType_OCLImage2dArrayDepthRW => Self::OCLImage2dArrayDepthRW,
+            clang_sys::CXType_OCLImage2dMSAARW => Self::OCLImage2dMSAARW,
+            clang_sys::CXType_OCLImage2dArrayMSAARW => Self::OCLImage2dArrayMSAARW,
+            clang_sys::CXType_OCLImage2dMSAADepthRW => Self::OCLImage2dMSAADepthRW,
+            clang_sys::CXType_OCLImage2dArrayMSAADepthRW => Self::OCLImage2dArrayMSAADepthRW,
+            clang_sys::CXType_OCLImage3dRW => Self::OCLImage3dRW,
+            clang_sys::CXType_OCLSampler => Self::OCLSampler,
+            clang_sys::CXType_OCLEvent => Self::OCLEvent,
+            clang_sys::CXType_OCLQueue => Self::OCLQueue,
+            clang_sys::CXType_OCLReserveID => Self::OCLReserveID,
+            clang_sys::CXType_ObjCObject => Self::ObjCObject,
+            clang_sys::CXType_ObjCTypeParam => Self::ObjCTypeParam,
+            clang_sys::CXType_Attributed => Self::Attributed,
+            clang_sys::CXType_OCLIntelSubgroupAVCMcePayload => Self::OCLIntelSubgroupAVCMcePayload,
+            clang_sys::CXType_OCLIntelSubgroupAVCImePayload => Self::OCLIntelSubgroupAVCImePayload,
+            clang_sys::CXType_OCLIntelSubgroupAVCRefPayload => Self::OCLIntelSubgroupAVCRefPayload,
+            clang_sys::CXType_OCLIntelSubgroupAVCSicPayload => Self::OCLIntelSubgroupAVCSicPayload,
+            clang_sys::CXType_OCLIntelSubgroupAVCMceResult => Self::OCLIntelSubgroupAVCMceResult,
+            clang_sys::CXType_OCLIntelSubgroupAVCImeResult => Self::OCLIntelSubgroupAVCImeResult,
+            clang_sys::CXType_OCLIntelSubgroupAVCRefResult => Self::OCLIntelSubgroupAVCRefResult,
+            clang_sys::CXType_OCLIntelSubgroupAVCSicResult => Self::OCLIntelSubgroupAVCSicResult,
+            clang_sys::CXType_OCLIntelSubgroupAVCImeResultSingleRefStreamout => {
+                Self::OCLIntelSubgroupAVCImeResultSingleRefStreamout
+            }
+            clang_sys::CXType_OCLIntelSubgroupAVCImeResultDualRefStreamout => {
+                Self::OCLIntelSubgroupAVCImeResultDualRefStreamout
+            }
+            clang_sys::CXType_OCLIntelSubgroupAVCImeSingleRefStreamin => {
+                Self::OCLIntelSubgroupAVCImeSingleRefStreamin
+            }
+            clang_sys::CXType_OCLIntelSubgroupAVCImeDualRefStreamin => {
+                Self::OCLIntelSubgroupAVCImeDualRefStreamin
+            }
+            clang_sys::CXType_ExtVector => Self::ExtVector,
+            clang_sys::CXType_Atomic => Self::Atomic,
+            clang_sys::CXType_BTFTagAttributed => Self::BTFTagAttributed,
+            clang_sys::CXType_HLSLResource => Self::HLSLResource,
+            clang_sys::CXType_HLSLAttributedResource => Self::HLSLAttributedResource,
+            k => Self::Unknown(k),
+        }
+    }
+    pub(crate) fn to_raw(self) -> clang_sys::CXTypeKind {
+        match self {
+            Self::Unknown(raw) => raw,
+            // a `#[repr(i32)]` enum starts with its discriminant
+            _ => unsafe { *(&self as *const Self as *const clang_sys::CXTypeKind) },
+        }
+    }
+
+    pub fn spelling(&self) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_getTypeKindSpelling(self.to_raw())) }
+    }
+}
+
+/// Reasons `sizeof`, `alignof` and `offsetof` queries can fail, mirrors `CXTypeLayoutError`
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum TypeLayoutError {
+    Invalid,
+    Incomplete,
+    Dependent,
+    NotConstantSize,
+    InvalidFieldName,
+    Undeduced,
+    /// an error added to libclang after this crate, with its raw negative result
+    Unknown(i64),
+}
+
+impl TypeLayoutError {
+    /// interpret the return value of `clang_Type_getSizeOf` family
+    fn from_layout_result(value: std::os::raw::c_longlong) -> Result<u64, Self> {
+        u64::try_from(value).map_err(|_| match i32::try_from(value) {
+            Ok(clang_sys::CXTypeLayoutError_Invalid) => Self::Invalid,
+            Ok(clang_sys::CXTypeLayoutError_Incomplete) => Self::Incomplete,
+            Ok(clang_sys::CXTypeLayoutError_Dependent) => Self::Dependent,
+            Ok(clang_sys::CXTypeLayoutError_NotConstantSize) => Self::NotConstantSize,
+            Ok(clang_sys::CXTypeLayoutError_InvalidFieldName) => Self::InvalidFieldName,
+            Ok(clang_sys::CXTypeLayoutError_Undeduced) => Self::Undeduced,
+            _ => Self::Unknown(value),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Type<'tu> {
+    raw: clang_sys::CXType,
+    _tu: PhantomData<&'tu TranslationUnit<'tu>>,
+}
+
+impl<'tu> PartialEq for Type<'tu> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { clang_sys::clang_equalTypes(self.raw, other.raw) != 0 }
+    }
+}
+
+impl<'tu> Eq for Type<'tu> {}
+
+impl<'tu> Type<'tu> {
+    /// `None` if `raw` is a `CXType_Invalid`
+    pub(crate) fn from_raw(raw: clang_sys::CXType) -> Option<Self> {
+        (raw.kind != clang_sys::CXType_Invalid).then_some(Self {
+            raw,
+            _tu: PhantomData,
+        })
+    }
+
+    pub fn kind(&self) -> TypeKind {
+        TypeKind::from_raw(self.raw.kind)
+    }
+    pub fn spelling(&self) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_getTypeSpelling(self.raw)) }
+    }
+    pub fn canonical_type(&self) -> Type<'tu> {
+        let raw = unsafe { clang_sys::clang_getCanonicalType(self.raw) };
+        Self::from_raw(raw).expect("canonical type of a valid type is valid")
+    }
+    /// the type being pointed to by a pointer, reference or member pointer
+    pub fn pointee_type(&self) -> Option<Type<'tu>> {
+        Self::from_raw(unsafe { clang_sys::clang_getPointeeType(self.raw) })
+    }
+    /// the element type of an array, complex or vector type
+    pub fn element_type(&self) -> Option<Type<'tu>> {
+        Self::from_raw(unsafe { clang_sys::clang_getElementType(self.raw) })
+    }
+    /// the number of elements of an array or vector type
+    pub fn num_elements(&self) -> Option<u64> {
+        let n = unsafe { clang_sys::clang_getNumElements(self.raw) };
+        u64::try_from(n).ok()
+    }
+    /// the size of a constant array
+    pub fn array_size(&self) -> Option<u64> {
+        let n = unsafe { clang_sys::clang_getArraySize(self.raw) };
+        u64::try_from(n).ok()
+    }
+    /// the return type of a function type
+    pub fn result_type(&self) -> Option<Type<'tu>> {
+        Self::from_raw(unsafe { clang_sys::clang_getResultType(self.raw) })
+    }
+    /// the parameter types of a function type
+    pub fn argument_types(&self) -> Option<Vec<Type<'tu>>> {
+        let n = unsafe { clang_sys::clang_getNumArgTypes(self.raw) };
+        let n = u32::try_from(n).ok()?;
+        (0..n)
+            .map(|i| Self::from_raw(unsafe { clang_sys::clang_getArgType(self.raw, i) }))
+            .collect()
+    }
+    pub fn is_variadic(&self) -> bool {
+        unsafe { clang_sys::clang_isFunctionTypeVariadic(self.raw) != 0 }
+    }
+    /// the type named by an elaborated type, e.g. `S` in `struct S`
+    pub fn named_type(&self) -> Option<Type<'tu>> {
+        Self::from_raw(unsafe { clang_sys::clang_Type_getNamedType(self.raw) })
+    }
+    pub fn typedef_name(&self) -> String {
+        unsafe { cxstring_into_string(clang_sys::clang_getTypedefName(self.raw)) }
+    }
+    pub fn declaration(&self) -> Option<Cursor<'tu>> {
+        Cursor::from_raw_valid(unsafe { clang_sys::clang_getTypeDeclaration(self.raw) })
+    }
+    pub fn is_const_qualified(&self) -> bool {
+        unsafe { clang_sys::clang_isConstQualifiedType(self.raw) != 0 }
+    }
+    pub fn is_volatile_qualified(&self) -> bool {
+        unsafe { clang_sys::clang_isVolatileQualifiedType(self.raw) != 0 }
+    }
+    pub fn is_restrict_qualified(&self) -> bool {
+        unsafe { clang_sys::clang_isRestrictQualifiedType(self.raw) != 0 }
+    }
+    pub fn is_pod(&self) -> bool {
+        unsafe { clang_sys::clang_isPODType(self.raw) != 0 }
+    }
+    /// size in bytes, as `sizeof`
+    pub fn size_of(&self) -> Result<u64, TypeLayoutError> {
+        TypeLayoutError::from_layout_result(unsafe { clang_sys::clang_Type_getSizeOf(self.raw) })
+    }
+    /// alignment in bytes, as `alignof`
+    pub fn align_of(&self) -> Result<u64, TypeLayoutError> {
+        TypeLayoutError::from_layout_result(unsafe { clang_sys::clang_Type_getAlignOf(self.raw) })
+    }
+    /// offset of `field` in bits
+    pub fn offset_of(&self, field: &str) -> Result<u64, TypeLayoutError> {
+        let field = str_to_cstring(field).map_err(|_| TypeLayoutError::InvalidFieldName)?;
+        TypeLayoutError::from_layout_result(unsafe {
+            clang_sys::clang_Type_getOffsetOf(self.raw, field.as_ptr())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn traits() {
+        use crate::utility::traits::*;
+
+        is_small_value_enum(&TypeKind::Int);
+        is_small_value_enum(&TypeLayoutError::Incomplete);
+
+        let ty = Type {
+            raw: clang_sys::CXType::default(),
+            _tu: PhantomData,
+        };
+        is_ffi_struct(&ty);
+    }
+
+    #[test]
+    fn layout_result() {
+        assert_eq!(TypeLayoutError::from_layout_result(8), Ok(8));
+        assert_eq!(
+            TypeLayoutError::from_layout_result(clang_sys::CXTypeLayoutError_Dependent.into()),
+            Err(TypeLayoutError::Dependent)
+        );
+        assert_eq!(
+            TypeLayoutError::from_layout_result(
+                clang_sys::CXTypeLayoutError_InvalidFieldName.into()
+            ),
+            Err(TypeLayoutError::InvalidFieldName)
+        );
+        assert_eq!(
+            TypeLayoutError::from_layout_result(-100),
+            Err(TypeLayoutError::Unknown(-100))
+        );
+        assert_eq!(
+            TypeLayoutError::from_layout_result(i64::MIN),
+            Err(TypeLayoutError::Unknown(i64::MIN))
+        );
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        for kind in [
+            TypeKind::Invalid,
+            TypeKind::Int,
+            TypeKind::HLSLAttributedResource,
+            TypeKind::Unknown(100_000),
+        ] {
+            assert_eq!(TypeKind::from_raw(kind.to_raw()), kind);
+        }
+        assert_eq!(TypeKind::from_raw(100_000), TypeKind::Unknown(100_000));
+        assert_eq!(TypeKind::Pointer.to_raw(), clang_sys::CXType_Pointer);
+    }
+
+    #[test]
+    fn invalid_type_is_none() {
+        assert!(Type::from_raw(clang_sys::CXType::default()).is_none());
+    }
+}
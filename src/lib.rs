@@ -18,9 +18,12 @@ pub fn with_chdir<P: AsRef<std::path::Path>, R, F: Fn() -> R>(p: P, f: F) -> R {
 
 pub mod clang;
 pub mod compilation_database;
+mod error;
 pub mod index;
 mod utility;
 
+pub use error::{Error, Result};
+
 #[doc = include_str!("../README.md")]
 #[cfg(doctest)]
 pub struct ReadmeDoctests;
@@ -1,17 +1,46 @@
 use std::ffi::{CStr, CString};
 use std::path::Path;
 
-/// convert a `&str` to `CString`
-///
-/// # Panics
-///
-/// it panics if `s` cannot be converted
-pub(crate) fn str_to_cstring(s: &str) -> CString {
-    CString::new(s).unwrap()
+use crate::error::{Error, Result};
+
+/// convert a `&str` to `CString`, fails if `s` contains a nul byte
+pub(crate) fn str_to_cstring(s: &str) -> Result<CString> {
+    CString::new(s).map_err(|_| Error::InvalidString(s.to_owned()))
+}
+
+/// convert a path to `CString`, fails if `p` is not valid UTF-8 or contains a nul byte
+pub(crate) fn path_to_cstring<P: AsRef<Path>>(p: P) -> Result<CString> {
+    let p = p.as_ref();
+    p.to_str()
+        .and_then(|s| CString::new(s).ok())
+        .ok_or_else(|| Error::InvalidPath(p.to_owned()))
 }
 
-pub(crate) fn path_to_cstring<P: AsRef<Path>>(p: P) -> CString {
-    str_to_cstring(p.as_ref().to_str().unwrap())
+/// `CString`s together with pointers to them, for passing `const char *const *` to libclang
+#[derive(Debug)]
+pub(crate) struct CStringArray {
+    _strings: Vec<CString>,
+    ptrs: Vec<*const std::os::raw::c_char>,
+}
+
+impl CStringArray {
+    pub(crate) fn new<S: AsRef<str>>(strings: &[S]) -> Result<Self> {
+        let strings = strings
+            .iter()
+            .map(|s| str_to_cstring(s.as_ref()))
+            .collect::<Result<Vec<_>>>()?;
+        let ptrs = strings.iter().map(|s| s.as_ptr()).collect();
+        Ok(Self {
+            _strings: strings,
+            ptrs,
+        })
+    }
+    pub(crate) fn as_ptr(&self) -> *const *const std::os::raw::c_char {
+        self.ptrs.as_ptr()
+    }
+    pub(crate) fn len(&self) -> usize {
+        self.ptrs.len()
+    }
 }
 
 /// convert a `CXString` to `String`
@@ -27,6 +56,126 @@ pub(crate) unsafe fn cxstring_into_string(cxstring: clang_sys::CXString) -> Stri
     s
 }
 
+/// keeps a panic of a callback called by libclang, as unwinding into C is not allowed,
+/// to be resumed once libclang returns
+#[derive(Debug, Default)]
+pub(crate) struct CaughtPanic(Option<Box<dyn std::any::Any + Send>>);
+
+impl CaughtPanic {
+    /// `None` if `f` panics, the panic is kept
+    pub(crate) fn catch<R, F: FnOnce() -> R>(&mut self, f: F) -> Option<R> {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+            .map_err(|panic| self.0 = Some(panic))
+            .ok()
+    }
+    /// continue unwinding with the kept panic, if any
+    pub(crate) fn resume(self) {
+        if let Some(panic) = self.0 {
+            std::panic::resume_unwind(panic);
+        }
+    }
+}
+
+/// define a bitflags-like newtype over a libclang flags enum
+///
+/// flags combine with `|`, and `contains` tests if all bits of another set are present
+macro_rules! define_flags {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident: $raw:ty {
+            $(
+                $(#[$flag_meta:meta])*
+                const $flag:ident = $value:expr;
+            )+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $name($raw);
+
+        impl $name {
+            pub const NONE: Self = Self(0);
+            $(
+                $(#[$flag_meta])*
+                pub const $flag: Self = Self($value as $raw);
+            )+
+
+            #[allow(dead_code)]
+            pub(crate) fn from_raw(raw: $raw) -> Self {
+                Self(raw)
+            }
+            pub fn bits(self) -> $raw {
+                self.0
+            }
+            pub fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+            pub fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+        }
+
+        impl std::ops::BitOr for $name {
+            type Output = Self;
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl std::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.insert(rhs);
+            }
+        }
+
+        impl std::ops::BitAnd for $name {
+            type Output = Self;
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+    };
+}
+
+pub(crate) use define_flags;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn to_cstring() {
+        assert_eq!(str_to_cstring("abc").unwrap().as_bytes(), b"abc");
+        assert_eq!(
+            str_to_cstring("a\0c"),
+            Err(Error::InvalidString("a\0c".to_owned()))
+        );
+        assert_eq!(path_to_cstring("a/b.cpp").unwrap().as_bytes(), b"a/b.cpp");
+        assert_eq!(
+            path_to_cstring("a\0c"),
+            Err(Error::InvalidPath("a\0c".into()))
+        );
+        assert!(CStringArray::new(&["-c", "a\0c"]).is_err());
+        assert_eq!(CStringArray::new(&["-c", "a.cpp"]).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn caught_panic() {
+        let mut caught = CaughtPanic::default();
+        assert_eq!(caught.catch(|| 1), Some(1));
+        assert_eq!(caught.catch(|| -> i32 { panic!("oops") }), None);
+        let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| caught.resume()));
+        assert_eq!(panic.unwrap_err().downcast_ref::<&str>(), Some(&"oops"));
+        CaughtPanic::default().resume();
+    }
+}
+
 #[allow(dead_code)]
 pub(crate) mod traits {
     pub(crate) fn is_small_value_struct<T>(_: &T)
@@ -44,6 +193,20 @@ pub(crate) mod traits {
             + Ord,
     {
     }
+    pub(crate) fn is_value_struct<T>(_: &T)
+    where
+        T: Sync
+            + Send
+            + Clone
+            + Default
+            + std::fmt::Debug
+            + std::hash::Hash
+            + PartialEq
+            + Eq
+            + PartialOrd
+            + Ord,
+    {
+    }
     pub(crate) fn is_small_value_enum<T>(_: &T)
     where
         T: Sync
@@ -58,6 +221,25 @@ pub(crate) mod traits {
             + Ord,
     {
     }
+    pub(crate) fn is_value_enum<T>(_: &T)
+    where
+        T: Sync
+            + Send
+            + Clone
+            + std::fmt::Debug
+            + std::hash::Hash
+            + PartialEq
+            + Eq
+            + PartialOrd
+            + Ord,
+    {
+    }
+    /// for enums holding floats, which can only be `PartialEq`
+    pub(crate) fn is_partial_eq_value_enum<T>(_: &T)
+    where
+        T: Sync + Send + Clone + std::fmt::Debug + PartialEq,
+    {
+    }
     pub(crate) fn is_ffi_struct<T>(_: &T)
     where
         T: std::fmt::Debug,
@@ -0,0 +1,122 @@
+use std::path::Path;
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::{
+    BlockComment, InlineCommandRenderKind, InlineComment, ParamDirection, ParseOptions,
+};
+
+const SOURCE: &str = r#"
+/// Adds two numbers.
+///
+/// Uses \b bold text and <em>inline HTML</em>.
+/// \param[in] a the first number
+/// \param b the second number
+/// \returns the sum
+/// \verbatim
+/// raw text
+/// \endverbatim
+int add(int a, int b);
+
+/// \tparam T the element type
+template <typename T> T first(T *items);
+
+int undocumented();
+"#;
+
+#[test]
+fn comment_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let tu = index
+        .parse(path, &["-std=c++17"], &[(path, SOURCE)], ParseOptions::NONE)
+        .unwrap();
+    let children = tu.create_cursor().children();
+    let find = |name: &str| {
+        *children
+            .iter()
+            .find(|cursor| cursor.spelling() == name)
+            .unwrap()
+    };
+
+    let add = find("add");
+    assert!(add
+        .raw_comment()
+        .unwrap()
+        .starts_with("/// Adds two numbers."));
+    assert_eq!(add.brief_comment().as_deref(), Some("Adds two numbers."));
+    let range = add.comment_range().unwrap();
+    assert_eq!(range.start().spelling_location().line, 2);
+    assert_eq!(range.end().spelling_location().line, 10);
+
+    let comment = add.parsed_comment().unwrap();
+    let blocks = comment.blocks();
+    assert!(matches!(
+        &blocks[0],
+        BlockComment::Paragraph(inlines) if inlines == &[InlineComment::Text(" Adds two numbers.".to_owned())]
+    ));
+    let BlockComment::Paragraph(inlines) = &blocks[1] else {
+        panic!("{:?}", blocks[1]);
+    };
+    assert!(inlines.contains(&InlineComment::Command {
+        name: "b".to_owned(),
+        args: vec!["bold".to_owned()],
+        render_kind: InlineCommandRenderKind::Bold,
+    }));
+    assert!(inlines.contains(&InlineComment::HtmlStartTag {
+        name: "em".to_owned(),
+        attributes: Vec::new(),
+        is_self_closing: false,
+    }));
+    assert!(inlines.contains(&InlineComment::HtmlEndTag {
+        name: "em".to_owned()
+    }));
+
+    let params = blocks
+        .iter()
+        .filter_map(|block| match block {
+            BlockComment::Param {
+                name,
+                index,
+                direction,
+                is_direction_explicit,
+                ..
+            } => Some((name.as_str(), *index, *direction, *is_direction_explicit)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        [
+            ("a", Some(0), ParamDirection::In, true),
+            ("b", Some(1), ParamDirection::In, false),
+        ]
+    );
+    assert!(blocks
+        .iter()
+        .any(|block| matches!(block, BlockComment::Command { name, .. } if name == "returns")));
+    assert!(blocks.iter().any(|block| matches!(
+        block,
+        BlockComment::VerbatimBlock { name, lines } if name == "verbatim" && lines.iter().any(|line| line.trim() == "raw text")
+    )));
+
+    assert!(comment
+        .to_html()
+        .contains("<p class=\"para-brief\"> Adds two numbers.</p>"));
+    let xml = comment.to_xml();
+    assert!(xml.starts_with("<Function"));
+    assert!(xml.contains("<Name>add</Name>"));
+
+    let first = find("first");
+    let blocks = first.parsed_comment().unwrap().blocks();
+    assert!(matches!(
+        &blocks[..],
+        [BlockComment::TParam { name, position: Some(position), .. }] if name == "T" && position == &[0]
+    ));
+
+    let undocumented = find("undocumented");
+    assert_eq!(undocumented.raw_comment(), None);
+    assert_eq!(undocumented.brief_comment(), None);
+    assert!(undocumented.comment_range().is_none());
+    assert!(undocumented.parsed_comment().is_none());
+}
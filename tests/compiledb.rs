@@ -1,39 +1,16 @@
 use clang_rs_binding::clang::Clang;
-use clang_rs_binding::index::{
-    from_payload, to_payload, ChildVisitResult, Cursor, Payload, SpellingLocation,
+use clang_rs_binding::compilation_database::{
+    CompileArgs, JsonCompilationDatabase, JsonCompileCommand,
 };
+use clang_rs_binding::index::{ChildVisit, Cursor, CursorKind, ParseOptions, SpellingLocation};
 use clang_rs_binding::with_chdir;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-fn visitor(cursor: &Cursor, _parent: &Cursor, payload: Payload) -> i32 {
-    if cursor.is_from_main_file() {
-        return ChildVisitResult::CONTINUE;
-    }
-    let spelling = cursor.spelling();
-    if cursor.is_cxx_method() || cursor.is_function_decl() || cursor.is_function_template() {
-        let extent = cursor.extent();
-        let start_loc = extent.start();
-        let end_loc = extent.end();
-
-        let start_spelling_loc = start_loc.spelling_location();
-        let end_spelling_loc = end_loc.spelling_location();
-        let payload = unsafe { from_payload::<AstDataPayload>(payload) };
-        let sl_to_string = |sl: SpellingLocation| {
-            format!(
-                "line: {}, column: {}, offset: {}",
-                sl.line, sl.column, sl.offset
-            )
-        };
-        let new_buf = payload.borrow().clone()
-            + &format!(
-                "{}: {} - {}\n",
-                spelling,
-                sl_to_string(start_spelling_loc),
-                sl_to_string(end_spelling_loc)
-            );
-        *payload.borrow_mut() = new_buf;
-    }
-    ChildVisitResult::RECURSIVE
+fn sl_to_string(sl: SpellingLocation) -> String {
+    format!(
+        "line: {}, column: {}, offset: {}",
+        sl.line, sl.column, sl.offset
+    )
 }
 
 fn get_system_headers() -> Vec<String> {
@@ -54,18 +31,42 @@ fn get_system_headers() -> Vec<String> {
         .collect::<Vec<_>>()
 }
 
-type AstDataPayload = std::cell::RefCell<String>;
-
 fn collect_ast(cursor: &Cursor<'_>) -> String {
-    let data = AstDataPayload::default();
-    cursor.visit_children(visitor, to_payload(&data));
-    data.take()
+    let mut buf = String::new();
+    cursor.visit_children(|cursor, _parent| {
+        if !cursor.is_from_main_file() {
+            return ChildVisit::Continue;
+        }
+        if matches!(
+            cursor.kind(),
+            CursorKind::CXXMethod | CursorKind::FunctionDecl | CursorKind::FunctionTemplate
+        ) {
+            let extent = cursor.extent();
+            buf += &format!(
+                "{}: {} - {}\n",
+                cursor.spelling(),
+                sl_to_string(extent.start().spelling_location()),
+                sl_to_string(extent.end().spelling_location())
+            );
+        }
+        ChildVisit::Recurse
+    });
+    buf
 }
 
 fn read_test_oracle<P: AsRef<Path>>(filename: P) -> String {
     std::fs::read_to_string(filename).unwrap()
 }
 
+/// a fresh directory for a test, unique across tests and concurrent test runs
+fn temp_build_dir(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("clang_rs_binding_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
 #[test]
 fn compile_db_works() {
     let compile_db_dir = std::path::Path::new("tests/artifacts/compiledb");
@@ -91,9 +92,233 @@ fn compile_db_works() {
     assert_eq!(compile_commands.get_size(), 1);
     let compile_command = compile_commands.get_command(0);
     let index = clang.create_index_with_display_diagnostics();
-    let tu = index.parse_translation_unit_from_compile_command(compile_command);
+    let tu = index.parse_translation_unit_from_compile_command(compile_command, ParseOptions::NONE);
+    let cursor = tu.create_cursor();
+    let buf = collect_ast(&cursor);
+
+    assert_eq!(buf, oracle);
+}
+
+#[test]
+fn compile_args_system_includes_work() {
+    let compile_db_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/artifacts/compiledb");
+    let oracle = read_test_oracle(compile_db_dir.join("compiledb.test_oracle"));
+    let build_dir = temp_build_dir("compile_args_system_includes");
+    std::process::Command::new("cmake")
+        .arg("-B")
+        .arg(&build_dir)
+        .arg("-S")
+        .arg(&compile_db_dir)
+        .output()
+        .unwrap_or_else(|e| panic!("failed to create compile database, {}", e));
+
+    let clang = Clang::new();
+    let compiledb = clang
+        .compilation_database_from_directory(&build_dir)
+        .unwrap();
+    let compile_commands = compiledb.get_compile_commands(compile_db_dir.join("main.cpp"));
+    assert_eq!(compile_commands.get_size(), 1);
+    // libclang does not find system headers by itself, borrow them from the driver
+    let mut compile_args = CompileArgs::from(compile_commands.get_command(0));
+    compile_args.add_system_includes("clang++").unwrap();
+    let index = clang.create_index_with_display_diagnostics();
+    let tu = index
+        .parse(
+            compile_args.file(),
+            compile_args.args(),
+            &[],
+            ParseOptions::NONE,
+        )
+        .unwrap();
     let cursor = tu.create_cursor();
     let buf = collect_ast(&cursor);
 
     assert_eq!(buf, oracle);
 }
+
+#[test]
+fn compile_command_accessors_work() {
+    let build_dir = temp_build_dir("compile_command_accessors");
+    let source = build_dir.join("a.cpp");
+    std::fs::write(
+        build_dir.join("compile_commands.json"),
+        format!(
+            r#"[{{"directory": "{dir}", "file": "{file}", "arguments": ["clang++", "-std=c++17", "-c", "{file}"]}}]"#,
+            dir = build_dir.display(),
+            file = source.display(),
+        ),
+    )
+    .unwrap();
+
+    let clang = Clang::new();
+    let compiledb = clang
+        .compilation_database_from_directory(&build_dir)
+        .unwrap();
+    let compile_commands = compiledb.get_compile_commands(&source);
+    assert_eq!(compile_commands.iter().len(), 1);
+    for compile_command in &compile_commands {
+        assert_eq!(compile_command.directory(), build_dir);
+        assert_eq!(compile_command.filename(), source);
+        assert_eq!(
+            compile_command.arguments(),
+            ["clang++", "-std=c++17", "-c", &source.to_string_lossy()]
+        );
+        assert!(compile_command.mapped_sources().is_empty());
+    }
+}
+
+#[test]
+fn parse_all_works() {
+    let build_dir = temp_build_dir("parse_all");
+    let entries = ["a", "b"]
+        .iter()
+        .map(|name| {
+            let source = build_dir.join(format!("{}.cpp", name));
+            std::fs::write(&source, format!("int {}_var;", name)).unwrap();
+            format!(
+                r#"{{"directory": "{}", "file": "{}", "arguments": ["clang++", "-c", "{}"]}}"#,
+                build_dir.display(),
+                source.display(),
+                source.display(),
+            )
+        })
+        .collect::<Vec<_>>();
+    std::fs::write(
+        build_dir.join("compile_commands.json"),
+        format!("[{}]", entries.join(",")),
+    )
+    .unwrap();
+
+    let clang = Clang::new();
+    let compiledb = clang
+        .compilation_database_from_directory(&build_dir)
+        .unwrap();
+    let compile_commands = compiledb.all_compile_commands();
+    let index = clang.create_index();
+    let mut parsed = index
+        .parse_all(&compile_commands, ParseOptions::NONE)
+        .map(|(compile_command, tu)| {
+            let tu = tu.unwrap();
+            let var = tu.create_cursor().children().pop().unwrap().spelling();
+            (compile_command.filename(), var)
+        })
+        .collect::<Vec<_>>();
+    parsed.sort();
+    assert_eq!(
+        parsed,
+        [
+            (build_dir.join("a.cpp"), "a_var".to_owned()),
+            (build_dir.join("b.cpp"), "b_var".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn json_compilation_database_works() {
+    let build_dir = temp_build_dir("json_compilation_database");
+    std::fs::write(build_dir.join("a.cpp"), "int a_var;").unwrap();
+    let db = [JsonCompileCommand {
+        directory: build_dir.clone(),
+        file: build_dir.join("a.cpp"),
+        arguments: ["clang++", "-std=c++17", "-c"]
+            .iter()
+            .map(|&s| s.to_owned())
+            .chain([build_dir.join("a.cpp").display().to_string()])
+            .collect(),
+        output: None,
+    }]
+    .into_iter()
+    .collect::<JsonCompilationDatabase>();
+    db.write_to_file(build_dir.join("compile_commands.json"))
+        .unwrap();
+    assert_eq!(
+        JsonCompilationDatabase::from_directory(&build_dir).unwrap(),
+        db
+    );
+
+    let clang = Clang::new();
+    let compiledb = clang
+        .compilation_database_from_directory(&build_dir)
+        .unwrap();
+    let from_libclang = compiledb
+        .all_compile_commands()
+        .iter()
+        .map(JsonCompileCommand::from)
+        .collect::<JsonCompilationDatabase>();
+    assert_eq!(from_libclang, db);
+
+    let index = clang.create_index();
+    let tu = index
+        .parse_json_compile_command(&db.commands()[0], ParseOptions::NONE)
+        .unwrap();
+    assert_eq!(
+        tu.create_cursor().children().pop().unwrap().spelling(),
+        "a_var"
+    );
+}
+
+#[test]
+fn parse_json_compile_command_resolves_directory() {
+    let build_dir = temp_build_dir("relative_compile_command");
+    std::fs::create_dir_all(build_dir.join("include")).unwrap();
+    std::fs::write(build_dir.join("include/rel.h"), "int rel_var;").unwrap();
+    std::fs::write(build_dir.join("rel.cpp"), "#include \"rel.h\"\n").unwrap();
+    assert_ne!(std::env::current_dir().unwrap(), build_dir);
+    let compile_command = JsonCompileCommand {
+        directory: build_dir.clone(),
+        file: "rel.cpp".into(),
+        arguments: ["clang++", "-Iinclude", "-c", "rel.cpp"]
+            .iter()
+            .map(|&s| s.to_owned())
+            .collect(),
+        output: None,
+    };
+
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let tu = index
+        .parse_json_compile_command(&compile_command, ParseOptions::NONE)
+        .unwrap();
+    assert_eq!(
+        tu.create_cursor().spelling(),
+        build_dir.join("rel.cpp").to_string_lossy()
+    );
+    assert_eq!(tu.diagnostics().len(), 0);
+    assert_eq!(
+        tu.create_cursor().children().pop().unwrap().spelling(),
+        "rel_var"
+    );
+}
+
+#[test]
+fn parse_all_resolves_directory() {
+    let build_dir = temp_build_dir("parse_all_relative");
+    std::fs::create_dir_all(build_dir.join("include")).unwrap();
+    std::fs::write(build_dir.join("include/rel.h"), "int rel_var;").unwrap();
+    std::fs::write(build_dir.join("rel.cpp"), "#include \"rel.h\"\n").unwrap();
+    std::fs::write(
+        build_dir.join("compile_commands.json"),
+        format!(
+            r#"[{{"directory": "{}", "file": "rel.cpp", "arguments": ["clang++", "-Iinclude", "-c", "rel.cpp"]}}]"#,
+            build_dir.display(),
+        ),
+    )
+    .unwrap();
+    assert_ne!(std::env::current_dir().unwrap(), build_dir);
+
+    let clang = Clang::new();
+    let compiledb = clang
+        .compilation_database_from_directory(&build_dir)
+        .unwrap();
+    let compile_commands = compiledb.all_compile_commands();
+    let index = clang.create_index();
+    let parsed = index
+        .parse_all(&compile_commands, ParseOptions::NONE)
+        .map(|(_, tu)| {
+            let tu = tu.unwrap();
+            assert_eq!(tu.diagnostics().len(), 0);
+            tu.create_cursor().children().pop().unwrap().spelling()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(parsed, ["rel_var"]);
+}
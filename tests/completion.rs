@@ -0,0 +1,94 @@
+use std::path::Path;
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::{
+    Availability, CodeCompleteOptions, CompletionChunkKind, CompletionContext, CursorKind,
+    ParseOptions,
+};
+
+const SOURCE: &str = r#"
+struct Point {
+    /// horizontal position
+    int x;
+    int y;
+    int distance(int other) const;
+};
+
+int main() {
+    Point p;
+    p.
+}
+"#;
+
+#[test]
+fn complete_at_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let unsaved_files = [(path, SOURCE)];
+    let tu = index
+        .parse(path, &["-std=c++17"], &unsaved_files, ParseOptions::NONE)
+        .unwrap();
+    let root = tu.create_cursor();
+
+    let results = tu
+        .complete_at(
+            path,
+            11,
+            7,
+            &unsaved_files,
+            CodeCompleteOptions::INCLUDE_BRIEF_COMMENTS,
+        )
+        .unwrap();
+    assert!(results
+        .contexts()
+        .contains(CompletionContext::DOT_MEMBER_ACCESS));
+    assert!(!results.is_empty());
+    assert_eq!(results.iter().len(), results.len());
+    assert!(results.diagnostics().len() > 0);
+    // cursors created before completing are still usable alongside the results
+    assert_eq!(root.kind(), CursorKind::TranslationUnit);
+
+    let typed_texts = results
+        .iter()
+        .filter_map(|result| result.typed_text())
+        .collect::<Vec<_>>();
+    for member in ["distance", "x", "y"] {
+        assert!(typed_texts.iter().any(|text| text == member), "{}", member);
+    }
+    let mut sorted = typed_texts.clone();
+    sorted.sort_by_key(|text| text.to_lowercase());
+    assert_eq!(typed_texts, sorted);
+
+    let x = results
+        .iter()
+        .find(|result| result.typed_text().as_deref() == Some("x"))
+        .unwrap();
+    assert_eq!(x.cursor_kind(), CursorKind::FieldDecl);
+    assert_eq!(x.availability(), Availability::Available);
+    assert_eq!(x.brief_comment().as_deref(), Some("horizontal position"));
+    assert!(x.fix_its().is_empty());
+
+    let distance = results
+        .iter()
+        .find(|result| result.typed_text().as_deref() == Some("distance"))
+        .unwrap();
+    assert_eq!(distance.cursor_kind(), CursorKind::CXXMethod);
+    let kinds = distance
+        .chunks()
+        .iter()
+        .map(|chunk| chunk.kind)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            CompletionChunkKind::ResultType,
+            CompletionChunkKind::TypedText,
+            CompletionChunkKind::LeftParen,
+            CompletionChunkKind::Placeholder,
+            CompletionChunkKind::RightParen,
+            CompletionChunkKind::Informative,
+        ]
+    );
+    assert_eq!(distance.chunks()[3].text, "int other");
+}
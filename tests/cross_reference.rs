@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::{CursorKind, FindResult, ParseOptions, Visit};
+
+const SOURCE: &str = r#"
+int add(int a, int b);
+
+int add(int a, int b) { return a + b; }
+
+int main() { return add(1, 2); }
+"#;
+
+#[test]
+fn cross_reference_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let tu = index
+        .parse(path, &["-std=c++17"], &[(path, SOURCE)], ParseOptions::NONE)
+        .unwrap();
+    let root = tu.create_cursor();
+    let adds = root
+        .children()
+        .into_iter()
+        .filter(|cursor| cursor.spelling() == "add")
+        .collect::<Vec<_>>();
+    assert_eq!(adds.len(), 2);
+    let (declaration, definition) = (adds[0], adds[1]);
+
+    assert!(!declaration.is_definition());
+    assert!(definition.is_definition());
+    assert_eq!(declaration.definition(), Some(definition));
+    assert_eq!(definition.canonical(), declaration);
+    assert_eq!(declaration.canonical(), declaration);
+    assert_eq!(declaration.usr(), definition.usr());
+    assert_eq!(declaration.usr().as_deref(), Some("c:@F@add#I#I#"));
+    assert_ne!(declaration, definition);
+
+    let call = root
+        .descendants()
+        .map(|(_, cursor)| cursor)
+        .find(|cursor| cursor.kind() == CursorKind::CallExpr)
+        .unwrap();
+    let callee = call.referenced().unwrap();
+    assert_eq!(callee.canonical(), declaration);
+    assert_eq!(callee.definition(), Some(definition));
+    assert_eq!(callee.semantic_parent(), Some(root));
+
+    let unique = adds
+        .iter()
+        .chain(adds.iter())
+        .map(|cursor| cursor.canonical())
+        .collect::<HashSet<_>>();
+    assert_eq!(unique.len(), 1);
+    assert!(root.usr().is_none());
+}
+
+#[test]
+fn find_references_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let header = Path::new("unsaved.h");
+    let tu = index
+        .parse(
+            path,
+            &["-std=c++17"],
+            &[
+                (path, &format!("#include \"unsaved.h\"\n{}", SOURCE)),
+                (header, "int twice(int x);"),
+            ],
+            ParseOptions::DETAILED_PREPROCESSING_RECORD,
+        )
+        .unwrap();
+    let file = tu.file(path).unwrap();
+    let definition = tu
+        .create_cursor()
+        .children()
+        .into_iter()
+        .find(|cursor| cursor.spelling() == "add" && cursor.is_definition())
+        .unwrap();
+
+    let mut lines = Vec::new();
+    let result = definition.find_references_in_file(&file, |cursor, range| {
+        assert_eq!(
+            cursor.referenced().unwrap().canonical(),
+            definition.canonical()
+        );
+        lines.push(range.start().spelling_location().line);
+        Visit::Continue
+    });
+    assert_eq!(result, FindResult::Completed);
+    // declaration, definition and the call
+    assert_eq!(lines, [3, 5, 7]);
+
+    let mut count = 0;
+    let result = definition.find_references_in_file(&file, |_, _| {
+        count += 1;
+        Visit::Break
+    });
+    assert_eq!(result, FindResult::Stopped);
+    assert_eq!(count, 1);
+
+    let mut includes = Vec::new();
+    let result = tu.find_includes_in_file(&file, |cursor, _| {
+        includes.push((cursor.kind(), cursor.spelling()));
+        Visit::Continue
+    });
+    assert_eq!(result, FindResult::Completed);
+    assert_eq!(
+        includes,
+        [(CursorKind::InclusionDirective, "unsaved.h".to_owned())]
+    );
+}
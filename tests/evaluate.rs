@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::{CursorKind, EvalResult, IntValue, ParseOptions};
+
+const SOURCE: &str = r#"
+#define BUFFER_SIZE 256
+
+constexpr int buffer_size = BUFFER_SIZE * 2;
+constexpr unsigned long long mask = ~0ull;
+constexpr double ratio = 1.5;
+constexpr const char *name = "config";
+int runtime();
+int not_constant = runtime();
+
+enum Level { Low = -1, High = 10 };
+"#;
+
+#[test]
+fn evaluate_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let tu = index
+        .parse(
+            path,
+            &["-std=c++17"],
+            &[(path, SOURCE)],
+            ParseOptions::DETAILED_PREPROCESSING_RECORD,
+        )
+        .unwrap();
+    let children = tu.create_cursor().children();
+    let find = |name: &str| {
+        *children
+            .iter()
+            .find(|cursor| cursor.spelling() == name)
+            .unwrap()
+    };
+
+    assert_eq!(
+        find("buffer_size").evaluate(),
+        Some(EvalResult::Int(IntValue::Signed(512)))
+    );
+    assert_eq!(
+        find("mask").evaluate(),
+        Some(EvalResult::Int(IntValue::Unsigned(u64::MAX)))
+    );
+    assert_eq!(find("ratio").evaluate(), Some(EvalResult::Float(1.5)));
+    assert_eq!(
+        find("name").evaluate(),
+        Some(EvalResult::StrLiteral("config".to_owned()))
+    );
+    assert_eq!(find("not_constant").evaluate(), None);
+
+    // the macro itself has no value, only the expression it is expanded in
+    let definition = find("BUFFER_SIZE");
+    assert_eq!(definition.kind(), CursorKind::MacroDefinition);
+    assert_eq!(definition.evaluate(), None);
+
+    let enumerators = find("Level").children();
+    assert_eq!(enumerators[0].kind(), CursorKind::EnumConstantDecl);
+    assert_eq!(enumerators[0].enum_constant_value(), Some(-1));
+    assert_eq!(enumerators[1].enum_constant_value(), Some(10));
+    assert_eq!(enumerators[1].enum_constant_unsigned_value(), Some(10));
+    assert_eq!(find("Level").enum_constant_value(), None);
+}
@@ -0,0 +1,36 @@
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::ParseOptions;
+
+#[test]
+fn file_works() {
+    let traverse_ast_dir = Path::new("tests/artifacts/traverse_ast");
+    let path = traverse_ast_dir.join("traverse_ast.cpp");
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let tu = index
+        .parse(&path, &["-std=c++17"], &[], ParseOptions::NONE)
+        .unwrap();
+
+    let file = tu.file(&path).unwrap();
+    assert_eq!(file.name(), path);
+    assert_eq!(
+        file.real_path().unwrap(),
+        std::fs::canonicalize(&path).unwrap()
+    );
+    let secs = |time: SystemTime| time.duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+    assert_eq!(secs(file.mtime()), secs(modified));
+    assert_eq!(file.contents().unwrap(), std::fs::read(&path).unwrap());
+    assert!(!file.is_multiple_include_guarded());
+    assert!(file.unique_id().is_some());
+
+    let cursor = tu.create_cursor().children().pop().unwrap();
+    let from_cursor = cursor.location().spelling_location().file.unwrap();
+    assert_eq!(from_cursor, file);
+    assert_eq!(from_cursor.unique_id(), file.unique_id());
+
+    assert!(tu.file("does_not_exist.cpp").is_none());
+}
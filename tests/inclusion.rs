@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::ParseOptions;
+
+#[test]
+fn include_graph_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let main = Path::new("main.cpp");
+    let a = Path::new("a.h");
+    let b = Path::new("b.h");
+    let tu = index
+        .parse(
+            main,
+            &["-std=c++17"],
+            &[
+                (main, "#include \"a.h\"\n#include \"b.h\"\n"),
+                (a, "#pragma once\n#include \"b.h\"\n"),
+                (b, "#pragma once\nint b;\n"),
+            ],
+            ParseOptions::DETAILED_PREPROCESSING_RECORD,
+        )
+        .unwrap();
+
+    let inclusions = tu.inclusions();
+    assert_eq!(inclusions[0].file.name(), main);
+    assert!(inclusions[0].stack.is_empty());
+    let b_inclusion = inclusions
+        .iter()
+        .find(|inclusion| inclusion.file.name() == b)
+        .unwrap();
+    // included through a.h first
+    assert_eq!(b_inclusion.stack.len(), 2);
+    let includer = b_inclusion.stack[0].file_location().file.unwrap();
+    assert_eq!(includer.name(), a);
+
+    let graph = tu.include_graph();
+    assert_eq!(graph.files().collect::<Vec<_>>(), [a, b, main]);
+    assert_eq!(graph.depth(main), Some(0));
+    assert_eq!(graph.depth(a), Some(1));
+    assert_eq!(graph.depth(b), Some(2));
+    // b.h is skipped the second time, but the directive in main.cpp is still an edge
+    assert_eq!(graph.includes(main).collect::<Vec<_>>(), [a, b]);
+    assert_eq!(graph.includes(a).collect::<Vec<_>>(), [b]);
+    assert!(graph.to_dot().contains("\"a.h\" -> \"b.h\";"));
+}
@@ -0,0 +1,153 @@
+use std::path::Path;
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::{
+    DeclInfo, Diagnostics, EntityKind, EntityRefInfo, EntityRefKind, File, IncludedFileInfo,
+    IndexOptions, IndexerCallbacks, ParseOptions, SymbolRoles, Visit,
+};
+
+const HEADER: &str = r#"
+#pragma once
+int add(int a, int b);
+"#;
+
+const SOURCE: &str = r#"
+#include "add.h"
+
+struct Counter {
+    int value;
+    void increment() { value = add(value, 1); }
+};
+"#;
+
+#[derive(Default)]
+struct Collector {
+    main_files: Vec<String>,
+    includes: Vec<(String, bool)>,
+    included_contents: Vec<Option<String>>,
+    started: usize,
+    declarations: Vec<(String, EntityKind, bool)>,
+    references: Vec<(String, EntityRefKind, SymbolRoles, Option<String>)>,
+    diagnostics: usize,
+    stop_at_declaration: Option<usize>,
+}
+
+impl IndexerCallbacks for Collector {
+    fn diagnostics(&mut self, diagnostics: Diagnostics<'_>) -> Visit {
+        self.diagnostics += diagnostics.len();
+        Visit::Continue
+    }
+    fn entered_main_file(&mut self, file: File<'_>) -> Visit {
+        self.main_files
+            .push(file.name().to_string_lossy().into_owned());
+        Visit::Continue
+    }
+    fn included_file(&mut self, info: &IncludedFileInfo<'_>) -> Visit {
+        self.includes.push((info.filename.clone(), info.is_angled));
+        let contents = info.file.as_ref().unwrap().contents();
+        self.included_contents
+            .push(contents.map(|contents| String::from_utf8_lossy(contents).into_owned()));
+        Visit::Continue
+    }
+    fn started_translation_unit(&mut self) -> Visit {
+        self.started += 1;
+        Visit::Continue
+    }
+    fn declaration(&mut self, info: &DeclInfo<'_>) -> Visit {
+        self.declarations.push((
+            info.entity.name.clone().unwrap_or_default(),
+            info.entity.kind,
+            info.is_definition,
+        ));
+        match self.stop_at_declaration {
+            Some(n) if self.declarations.len() >= n => Visit::Break,
+            _ => Visit::Continue,
+        }
+    }
+    fn entity_reference(&mut self, info: &EntityRefInfo<'_>) -> Visit {
+        self.references.push((
+            info.referenced.name.clone().unwrap_or_default(),
+            info.kind,
+            info.roles,
+            info.parent.as_ref().and_then(|parent| parent.name.clone()),
+        ));
+        Visit::Continue
+    }
+}
+
+#[test]
+fn index_source_file_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let action = index.create_index_action();
+    let path = Path::new("unsaved.cpp");
+    let unsaved_files = [(path, SOURCE), (Path::new("add.h"), HEADER)];
+    let mut collector = Collector::default();
+    let tu = action
+        .index_source_file(
+            path,
+            &["-std=c++17"],
+            &unsaved_files,
+            ParseOptions::NONE,
+            IndexOptions::NONE,
+            &mut collector,
+        )
+        .unwrap();
+    assert_eq!(tu.create_cursor().spelling(), "unsaved.cpp");
+
+    assert_eq!(collector.main_files, ["unsaved.cpp"]);
+    assert_eq!(collector.includes, [("add.h".to_owned(), false)]);
+    // the translation unit does not exist yet while a source file is indexed
+    assert_eq!(collector.included_contents, [None]);
+    assert_eq!(collector.started, 1);
+    assert_eq!(collector.diagnostics, 0);
+    for declaration in [
+        ("add".to_owned(), EntityKind::Function, false),
+        ("Counter".to_owned(), EntityKind::Struct, true),
+        ("value".to_owned(), EntityKind::Field, true),
+        ("increment".to_owned(), EntityKind::CXXInstanceMethod, true),
+    ] {
+        assert!(
+            collector.declarations.contains(&declaration),
+            "{:?}",
+            declaration
+        );
+    }
+    let (_, kind, roles, parent) = collector
+        .references
+        .iter()
+        .find(|(name, ..)| name == "add")
+        .unwrap();
+    assert_eq!(*kind, EntityRefKind::Direct);
+    assert!(roles.contains(SymbolRoles::CALL));
+    assert_eq!(parent.as_deref(), Some("increment"));
+}
+
+#[test]
+fn index_translation_unit_can_abort() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let unsaved_files = [(path, SOURCE), (Path::new("add.h"), HEADER)];
+    let tu = index
+        .parse(path, &["-std=c++17"], &unsaved_files, ParseOptions::NONE)
+        .unwrap();
+    let action = index.create_index_action();
+
+    let mut collector = Collector::default();
+    action
+        .index_translation_unit(&tu, IndexOptions::NONE, &mut collector)
+        .unwrap();
+    assert!(collector.declarations.len() > 1);
+    assert!(!collector.references.is_empty());
+
+    let mut collector = Collector {
+        stop_at_declaration: Some(1),
+        ..Collector::default()
+    };
+    action
+        .index_translation_unit(&tu, IndexOptions::NONE, &mut collector)
+        .unwrap();
+    assert_eq!(collector.declarations.len(), 1);
+    assert!(collector.references.is_empty());
+}
@@ -0,0 +1,160 @@
+use std::path::Path;
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::{CursorKind, DiagnosticSeverity, ParseOptions, TypeKind};
+use clang_rs_binding::Error;
+
+const SOURCE: &str = r#"
+struct Point {
+  int x;
+  double y;
+};
+
+int unused_variable() {
+  int z;
+  return 0;
+}
+"#;
+
+#[test]
+fn parse_unsaved_file_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let tu = index
+        .parse(path, &["-std=c++17"], &[(path, SOURCE)], ParseOptions::NONE)
+        .unwrap();
+    let decls = tu
+        .create_cursor()
+        .children()
+        .into_iter()
+        .filter(|cursor| matches!(cursor.spelling().as_str(), "Point" | "unused_variable"))
+        .map(|cursor| cursor.kind())
+        .collect::<Vec<_>>();
+    assert_eq!(decls, [CursorKind::StructDecl, CursorKind::FunctionDecl]);
+}
+
+#[test]
+fn diagnostics_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let tu = index
+        .parse(path, &["-Wall"], &[(path, SOURCE)], ParseOptions::NONE)
+        .unwrap();
+    let diagnostics = tu.diagnostics().collect::<Vec<_>>();
+    assert_eq!(diagnostics.len(), 1);
+
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.severity(), DiagnosticSeverity::Warning);
+    assert_eq!(diagnostic.message(), "unused variable 'z'");
+    let option = diagnostic.option().unwrap();
+    assert_eq!(option.enable, "-Wunused-variable");
+    assert_eq!(option.disable, "-Wno-unused-variable");
+    assert_eq!(diagnostic.location().spelling_location().line, 8);
+    assert!(diagnostic.fix_its().is_empty());
+}
+
+#[test]
+fn type_layout_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let tu = index
+        .parse(
+            path,
+            &["-target", "x86_64-unknown-linux-gnu"],
+            &[(path, SOURCE)],
+            ParseOptions::NONE,
+        )
+        .unwrap();
+    let point = tu
+        .create_cursor()
+        .children()
+        .into_iter()
+        .find(|cursor| cursor.spelling() == "Point")
+        .unwrap();
+    let ty = point.ty().unwrap();
+    assert_eq!(ty.kind(), TypeKind::Record);
+    assert_eq!(ty.size_of(), Ok(16));
+    assert_eq!(ty.align_of(), Ok(8));
+    assert_eq!(ty.offset_of("y"), Ok(64));
+
+    let fields = point
+        .children()
+        .into_iter()
+        .map(|cursor| cursor.ty().unwrap().kind())
+        .collect::<Vec<_>>();
+    assert_eq!(fields, [TypeKind::Int, TypeKind::Double]);
+}
+
+#[test]
+fn reparse_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let spellings = |tu: &clang_rs_binding::index::TranslationUnit| {
+        tu.create_cursor()
+            .children()
+            .into_iter()
+            .map(|cursor| cursor.spelling())
+            .filter(|spelling| spelling.starts_with("var_"))
+            .collect::<Vec<_>>()
+    };
+
+    let tu = index
+        .parse(
+            path,
+            &["-std=c++17"],
+            &[(path, "int var_a;")],
+            ParseOptions::NONE,
+        )
+        .unwrap();
+    assert_eq!(spellings(&tu), ["var_a"]);
+
+    let tu = tu.reparse(&[(path, "int var_b;\nint var_c;")]).unwrap();
+    assert_eq!(spellings(&tu), ["var_b", "var_c"]);
+}
+
+#[test]
+fn save_and_reload_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let ast_filename = Path::new("save_and_reload_works.ast");
+    let spellings = |tu: &clang_rs_binding::index::TranslationUnit| {
+        tu.create_cursor()
+            .children()
+            .into_iter()
+            .map(|cursor| cursor.spelling())
+            .filter(|spelling| matches!(spelling.as_str(), "Point" | "unused_variable"))
+            .collect::<Vec<_>>()
+    };
+
+    let tu = index
+        .parse(path, &["-std=c++17"], &[(path, SOURCE)], ParseOptions::NONE)
+        .unwrap();
+    tu.save(ast_filename).unwrap();
+
+    let reloaded = index.try_create_translation_unit(ast_filename).unwrap();
+    assert_eq!(spellings(&reloaded), spellings(&tu));
+    assert_eq!(spellings(&reloaded), ["Point", "unused_variable"]);
+}
+
+#[test]
+fn invalid_inputs_are_errors() {
+    let clang = Clang::try_new().unwrap();
+    let index = clang.create_index();
+    assert!(matches!(
+        index.try_create_translation_unit("does_not_exist.ast"),
+        Err(Error::AstRead { .. })
+    ));
+    assert!(matches!(
+        index.parse("unsaved.cpp", &["-DX=\0"], &[], ParseOptions::NONE),
+        Err(Error::InvalidString(_))
+    ));
+    assert!(matches!(
+        clang.compilation_database_from_directory("does_not_exist"),
+        Err(Error::LoadDatabase { .. })
+    ));
+}
@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::ParseOptions;
+
+const SOURCE: &str = r#"
+namespace geometry {
+struct Point {
+    int x;
+    int y;
+};
+
+int distance(const Point &from, const Point &to) { return to.x - from.x; }
+}
+"#;
+
+#[test]
+fn pretty_print_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let tu = index
+        .parse(path, &["-std=c++17"], &[(path, SOURCE)], ParseOptions::NONE)
+        .unwrap();
+    let namespace = tu.create_cursor().children()[0];
+    let distance = namespace
+        .children()
+        .into_iter()
+        .find(|cursor| cursor.spelling() == "distance")
+        .unwrap();
+
+    assert_eq!(
+        distance.display_name(),
+        "distance(const Point &, const Point &)"
+    );
+    assert_eq!(namespace.display_name(), "geometry");
+
+    let mut policy = distance.printing_policy();
+    assert!(!policy.terse_output());
+    assert!(distance
+        .pretty_print(&policy)
+        .contains("return to.x - from.x;"));
+
+    policy.set_terse_output(true);
+    policy.set_fully_qualified_name(true);
+    assert!(policy.terse_output());
+    assert!(policy.fully_qualified_name());
+    let printed = distance.pretty_print(&policy);
+    assert!(!printed.contains("return"), "{}", printed);
+    assert!(
+        printed.starts_with("int geometry::distance("),
+        "{}",
+        printed
+    );
+
+    policy.set_indentation(4);
+    assert_eq!(policy.indentation(), 4);
+    policy.set_terse_output(false);
+    let point = namespace.children()[0];
+    assert_eq!(
+        point.pretty_print(&policy),
+        "struct Point {\n    int x;\n    int y;\n}"
+    );
+}
@@ -0,0 +1,65 @@
+use std::cmp::Ordering;
+use std::path::Path;
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::{CursorKind, ParseOptions};
+
+const SOURCE: &str = r#"#define DECLARE(name) int name
+DECLARE(from_macro);
+int plain;
+#line 100 "renamed.cpp"
+int after_line;
+"#;
+
+#[test]
+fn source_location_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let tu = index
+        .parse(path, &["-std=c++17"], &[(path, SOURCE)], ParseOptions::NONE)
+        .unwrap();
+    let decls = tu
+        .create_cursor()
+        .children()
+        .into_iter()
+        .filter(|cursor| cursor.kind() == CursorKind::VarDecl)
+        .collect::<Vec<_>>();
+    assert_eq!(decls.len(), 3);
+
+    let from_macro = decls[0].location();
+    assert!(from_macro.is_from_main_file());
+    assert!(!from_macro.is_in_system_header());
+    assert_eq!(from_macro.expansion_location().line, 2);
+    assert_eq!(from_macro.file_location().line, 2);
+    assert_eq!(from_macro.spelling_location().line, 2);
+    assert_eq!(from_macro.spelling_location().column, 9);
+
+    let plain = decls[1].location();
+    assert_eq!(from_macro.cmp_file_offset(&plain), Some(Ordering::Less));
+    assert_eq!(plain.cmp_file_offset(&from_macro), Some(Ordering::Greater));
+    assert_eq!(plain, plain);
+    assert_ne!(plain, from_macro);
+
+    let after_line = decls[2].location();
+    let presumed = after_line.presumed_location();
+    assert_eq!(presumed.filename, "renamed.cpp");
+    assert_eq!(presumed.line, 100);
+    assert_eq!(after_line.spelling_location().line, 5);
+
+    let file = plain.spelling_location().file.unwrap();
+    assert_eq!(tu.location(&file, 3, 5), plain);
+    let offset = plain.spelling_location().offset;
+    assert_eq!(tu.location_for_offset(&file, offset), plain);
+
+    // the macro argument is a distinct location from where it was spelled, at the same offset
+    let spelled = tu.location(&file, 2, 9);
+    assert_ne!(from_macro, spelled);
+    assert_eq!(from_macro.cmp_file_offset(&spelled), Some(Ordering::Equal));
+
+    // the translation unit cursor has no location, so it is outside of any file
+    let root = tu.create_cursor();
+    let nowhere = root.location();
+    assert_eq!(nowhere.cmp_file_offset(&nowhere), None);
+    assert_eq!(plain.cmp_file_offset(&nowhere), None);
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use clang_rs_binding::clang::Clang;
+use clang_rs_binding::index::{CursorKind, ParseOptions, TokenKind};
+
+#[test]
+fn tokenize_works() {
+    let clang = Clang::new();
+    let index = clang.create_index();
+    let path = Path::new("unsaved.cpp");
+    let tu = index
+        .parse(
+            path,
+            &["-std=c++17"],
+            &[(path, "int x = 42;")],
+            ParseOptions::NONE,
+        )
+        .unwrap();
+    let tokens = tu.tokenize(&tu.create_cursor().extent());
+
+    let kinds = tokens.iter().map(|t| t.kind()).collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            TokenKind::Keyword,
+            TokenKind::Identifier,
+            TokenKind::Punctuation,
+            TokenKind::Literal,
+            TokenKind::Punctuation,
+        ]
+    );
+    let spellings = tokens.iter().map(|t| t.spelling()).collect::<Vec<_>>();
+    assert_eq!(spellings, ["int", "x", "=", "42", ";"]);
+    assert_eq!(
+        tokens.get(3).unwrap().location().spelling_location().column,
+        9
+    );
+
+    let cursors = tokens.annotate();
+    assert_eq!(cursors.len(), tokens.len());
+    assert_eq!(cursors[1].unwrap().kind(), CursorKind::VarDecl);
+    assert_eq!(cursors[3].unwrap().kind(), CursorKind::IntegerLiteral);
+}
@@ -1,54 +1,38 @@
 use std::path::Path;
 
 use clang_rs_binding::clang::Clang;
-use clang_rs_binding::index::{from_payload, to_payload, ChildVisitResult, Cursor, Payload};
+use clang_rs_binding::index::{ChildVisit, Cursor};
 
-fn visitor(cursor: &Cursor, _parent: &Cursor, payload: Payload) -> i32 {
-    if cursor.is_from_main_file() {
-        return ChildVisitResult::CONTINUE;
-    }
-    let cursor_kind_spelling = cursor.kind_spelling();
-    let cursor_spelling = cursor.spelling();
-    let payload = unsafe { from_payload::<AstDataPayload>(payload) };
-    let new_buf = payload.borrow().buf.clone()
-        + &format!(
+fn collect_ast_at_level(cursor: &Cursor<'_>, level: usize, buf: &mut String) {
+    cursor.visit_children(|cursor, _parent| {
+        if !cursor.is_from_main_file() {
+            return ChildVisit::Continue;
+        }
+        *buf += &format!(
             "{:-<width$} {} ({})\n",
             "",
-            cursor_kind_spelling,
-            cursor_spelling,
-            width = payload.borrow().level as usize,
+            cursor.kind_spelling(),
+            cursor.spelling(),
+            width = level,
         );
-    payload.borrow_mut().buf = new_buf;
-    let children_payload = AstDataPayload::new(AstData {
-        level: payload.borrow().level + 1,
-        buf: String::new(),
+        collect_ast_at_level(&cursor, level + 1, buf);
+        ChildVisit::Continue
     });
-    cursor.visit_children(visitor, to_payload(&children_payload));
-    payload.borrow_mut().buf += &children_payload.borrow().buf;
-    ChildVisitResult::CONTINUE
-}
-
-#[derive(Debug, Default)]
-struct AstData {
-    level: i32,
-    buf: String,
 }
-type AstDataPayload = std::cell::RefCell<AstData>;
 
 fn collect_ast(cursor: &Cursor<'_>) -> String {
-    let data = AstDataPayload::new(AstData {
-        level: 0,
-        buf: String::new(),
-    });
-    cursor.visit_children(visitor, to_payload(&data));
-    data.take().buf
+    let mut buf = String::new();
+    collect_ast_at_level(cursor, 0, &mut buf);
+    buf
 }
 
-fn generate_ast<P: AsRef<Path>>(filename: P) -> impl AsRef<Path> {
-    let ast_filename = Path::new("traverse_ast.ast");
+fn generate_ast<P: AsRef<Path>>(filename: P, ast_filename: &str) -> impl AsRef<Path> + '_ {
+    let ast_filename = Path::new(ast_filename);
     std::process::Command::new("clang++")
         .arg("-emit-ast")
         .arg(filename.as_ref())
+        .arg("-o")
+        .arg(ast_filename)
         .status()
         .unwrap_or_else(|e| {
             panic!(
@@ -68,7 +52,10 @@ fn read_test_oracle<P: AsRef<Path>>(filename: P) -> String {
 #[test]
 fn traverse_ast_works() {
     let traverse_ast_dir = std::path::Path::new("tests/artifacts/traverse_ast");
-    let ast_filename = generate_ast(traverse_ast_dir.join("traverse_ast.cpp"));
+    let ast_filename = generate_ast(
+        traverse_ast_dir.join("traverse_ast.cpp"),
+        "traverse_ast.ast",
+    );
     let oracle = read_test_oracle(traverse_ast_dir.join("traverse_ast.test_oracle"));
 
     let clang = Clang::default();
@@ -79,3 +66,62 @@ fn traverse_ast_works() {
 
     assert_eq!(buf, oracle);
 }
+
+#[test]
+fn traverse_ast_with_descendants_works() {
+    let traverse_ast_dir = std::path::Path::new("tests/artifacts/traverse_ast");
+    let ast_filename = generate_ast(
+        traverse_ast_dir.join("traverse_ast.cpp"),
+        "traverse_ast_with_descendants.ast",
+    );
+    let oracle = read_test_oracle(traverse_ast_dir.join("traverse_ast.test_oracle"));
+
+    let clang = Clang::default();
+    let index = clang.create_index();
+    let tu = index.create_translation_unit(&ast_filename);
+    let cursor = tu.create_cursor();
+
+    let mut buf = String::new();
+    let mut descendants = cursor.descendants();
+    while let Some((depth, cursor)) = descendants.next() {
+        if !cursor.is_from_main_file() {
+            descendants.skip_subtree();
+            continue;
+        }
+        buf += &format!(
+            "{:-<width$} {} ({})\n",
+            "",
+            cursor.kind_spelling(),
+            cursor.spelling(),
+            width = depth - 1,
+        );
+    }
+    assert_eq!(buf, oracle);
+
+    let function = cursor
+        .descendants_breadth_first()
+        .map(|(_, cursor)| cursor)
+        .find(|cursor| cursor.spelling() == "x" && cursor.is_declaration())
+        .unwrap();
+    let ancestors = function
+        .ancestors()
+        .map(|cursor| cursor.kind_spelling())
+        .collect::<Vec<_>>();
+    assert_eq!(ancestors, ["FunctionTemplate", "TranslationUnit"]);
+}
+
+#[test]
+#[should_panic(expected = "panic from visitor")]
+fn visit_children_resumes_panic() {
+    let traverse_ast_dir = std::path::Path::new("tests/artifacts/traverse_ast");
+    let ast_filename = generate_ast(
+        traverse_ast_dir.join("traverse_ast.cpp"),
+        "visit_children_resumes_panic.ast",
+    );
+
+    let clang = Clang::default();
+    let index = clang.create_index();
+    let tu = index.create_translation_unit(&ast_filename);
+    tu.create_cursor()
+        .visit_children(|_cursor, _parent| panic!("panic from visitor"));
+}